@@ -0,0 +1,120 @@
+//! Compare allocations and time of deserializing large list responses into the owned
+//! models and into the borrowed models of `types::borrowed`.
+//!
+//! Like `ResponseRef` itself, the borrowed side skips the `included` section that the owned
+//! response parses.
+//!
+//! ```text
+//! cargo bench --bench allocations
+//! ```
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fs;
+use std::hint::black_box;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use serde_json::Value;
+
+use geckoterminal_rs::types::{
+    borrowed::{PoolRef, ResponseRef, TradeRef},
+    pool::Pool,
+    response::GeckoTerminalResponse,
+    trade::Trade,
+};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const ITERATIONS: u32 = 200;
+
+/// A fixture with its `data` list repeated to `len` items. `included` is removed, since the
+/// borrowed `ResponseRef` skips it and the owned model would otherwise parse more.
+fn payload(fixture: &str, len: usize) -> Vec<u8> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(format!("{fixture}.json"));
+    let mut body: Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+    let items = body["data"].as_array().unwrap().clone();
+    body["data"] = items.into_iter().cycle().take(len).collect();
+    body.as_object_mut().unwrap().remove("included");
+    serde_json::to_vec(&body).unwrap()
+}
+
+/// Run `parse` and return allocations, allocated bytes and time per iteration.
+fn measure(parse: impl Fn() -> usize) -> (usize, usize, Duration) {
+    let (allocations, bytes) = (
+        ALLOCATIONS.load(Ordering::Relaxed),
+        ALLOCATED_BYTES.load(Ordering::Relaxed),
+    );
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(parse());
+    }
+    let elapsed = start.elapsed() / ITERATIONS;
+    let iterations = ITERATIONS as usize;
+    (
+        (ALLOCATIONS.load(Ordering::Relaxed) - allocations) / iterations,
+        (ALLOCATED_BYTES.load(Ordering::Relaxed) - bytes) / iterations,
+        elapsed,
+    )
+}
+
+fn report(name: &str, owned: (usize, usize, Duration), borrowed: (usize, usize, Duration)) {
+    println!("{name}");
+    for (model, (allocations, bytes, elapsed)) in [("owned", owned), ("borrowed", borrowed)] {
+        println!("  {model:<9} {allocations:>7} allocs {bytes:>10} bytes {elapsed:>12.2?}");
+    }
+}
+
+fn main() {
+    let trades = payload("network_pool_trades", 300);
+    report(
+        "network_pool_trades, 300 trades",
+        measure(|| {
+            let resp: GeckoTerminalResponse<Vec<Trade>> = serde_json::from_slice(&trades).unwrap();
+            resp.data.len()
+        }),
+        measure(|| {
+            let resp: ResponseRef<Vec<TradeRef>> = serde_json::from_slice(&trades).unwrap();
+            resp.data.len()
+        }),
+    );
+
+    let pools = payload("network_pools", 100);
+    report(
+        "network_pools, 100 pools",
+        measure(|| {
+            let resp: GeckoTerminalResponse<Vec<Pool>> = serde_json::from_slice(&pools).unwrap();
+            resp.data.len()
+        }),
+        measure(|| {
+            let resp: ResponseRef<Vec<PoolRef>> = serde_json::from_slice(&pools).unwrap();
+            resp.data.len()
+        }),
+    );
+}
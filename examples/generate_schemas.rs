@@ -0,0 +1,22 @@
+//! Write the JSON Schema of every endpoint response to a directory, one file per endpoint.
+//!
+//! ```text
+//! cargo run --example generate_schemas --features schema -- schemas
+//! ```
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+use geckoterminal_rs::schema::endpoint_schemas;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let dir = PathBuf::from(env::args().nth(1).unwrap_or_else(|| "schemas".to_string()));
+    fs::create_dir_all(&dir)?;
+    for (endpoint, schema) in endpoint_schemas() {
+        let file = dir.join(format!("{endpoint}.json"));
+        fs::write(&file, serde_json::to_string_pretty(&schema)? + "\n")?;
+        println!("{}", file.display());
+    }
+    Ok(())
+}
@@ -0,0 +1,447 @@
+//! Fetch fresh responses for the fixtures in `tests/fixtures` and report how their shape
+//! differs from the current fixtures and from the Rust structs.
+//!
+//! ```text
+//! cargo run --example refresh_fixtures                    # report only
+//! cargo run --example refresh_fixtures -- --write         # report and overwrite fixtures
+//! cargo run --example refresh_fixtures -- network_pools   # only the named fixtures
+//! cargo run --example refresh_fixtures -- --local         # check the current fixtures only
+//! ```
+//!
+//! Each response is deserialized item by item into the typed model. The report lists the
+//! fields that ended up in `extra` maps, i.e. fields the structs do not model, and the path
+//! of every field that failed to deserialize. Fixtures of paid endpoints are refreshed when
+//! `CG_PRO_API_KEY` is set.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+
+use geckoterminal_rs::types::{
+    category::Category,
+    dex::Dex,
+    holder::TopHolders,
+    network::Network,
+    ohlcv::OHLCV,
+    pool::Pool,
+    response::{Included, Links, Meta},
+    simple::TokenPrice,
+    token::Token,
+    token_info::TokenInfo,
+    trade::Trade,
+    trader::TopTraders,
+};
+use geckoterminal_rs::GeckoTerminalAPI;
+
+const USDC: &str = "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48";
+const WETH: &str = "0xc02aaa39b223fe8d0a0e5c4f27ead9083c756cc2";
+const PEPE: &str = "0x6982508145454ce325ddbe47a25d4ec3d2311933";
+const USDC_WETH_POOL: &str = "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640";
+const PEPE_WETH_POOL: &str = "0x11950d141ecb863f01007add7d1a342041227b58";
+const POOL_INCLUDES: &str = "base_token,quote_token,dex";
+
+/// A resource type whose unmodeled fields can be listed.
+trait Modeled: DeserializeOwned {
+    /// The `extra` maps of the resource and of the structs nested in it.
+    fn extras(&self) -> Vec<&HashMap<String, Value>>;
+}
+
+macro_rules! modeled {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Modeled for $ty {
+                fn extras(&self) -> Vec<&HashMap<String, Value>> {
+                    vec![&self.attributes.extra]
+                }
+            }
+        )*
+    };
+}
+
+modeled!(Network, Dex, Pool, Token, TokenInfo, TokenPrice, Trade, OHLCV, Category);
+
+impl Modeled for TopHolders {
+    fn extras(&self) -> Vec<&HashMap<String, Value>> {
+        let holders = self.attributes.holders.iter().map(|holder| &holder.extra);
+        [&self.attributes.extra]
+            .into_iter()
+            .chain(holders)
+            .collect()
+    }
+}
+
+impl Modeled for TopTraders {
+    fn extras(&self) -> Vec<&HashMap<String, Value>> {
+        let traders = self.attributes.traders.iter().map(|trader| &trader.extra);
+        [&self.attributes.extra]
+            .into_iter()
+            .chain(traders)
+            .collect()
+    }
+}
+
+impl Modeled for Included {
+    fn extras(&self) -> Vec<&HashMap<String, Value>> {
+        match self {
+            Included::Token(token) => vec![&token.attributes.extra],
+            Included::Pool(pool) => vec![&pool.attributes.extra],
+            Included::Dex(dex) => vec![&dex.attributes.extra],
+            Included::Network(network) => vec![&network.attributes.extra],
+            _ => vec![],
+        }
+    }
+}
+
+/// What deserializing a response into the typed model found.
+#[derive(Default)]
+struct Report {
+    /// Attribute names that ended up in `extra` maps.
+    unmodeled: BTreeSet<String>,
+    /// Paths of the fields that failed to deserialize, with the error.
+    failed: Vec<String>,
+}
+
+impl Report {
+    fn check<T: DeserializeOwned>(&mut self, value: &Value, path: &str) -> Option<T> {
+        serde_path_to_error::deserialize(value)
+            .map_err(|err| {
+                self.failed
+                    .push(format!("{path}.{}: {}", err.path(), err.inner()))
+            })
+            .ok()
+    }
+
+    fn check_modeled<T: Modeled>(&mut self, value: &Value, path: &str) {
+        if let Some(resource) = self.check::<T>(value, path) {
+            let keys = resource.extras().into_iter().flat_map(HashMap::keys);
+            self.unmodeled.extend(keys.cloned());
+        }
+    }
+}
+
+/// Deserialize a response body one `data` and `included` item at a time, so that one bad
+/// field does not hide the others.
+fn check<T: Modeled, M: DeserializeOwned>(body: &Value) -> Report {
+    let mut report = Report::default();
+    match &body["data"] {
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                report.check_modeled::<T>(item, &format!("data[{i}]"));
+            }
+        }
+        data => report.check_modeled::<T>(data, "data"),
+    }
+    if let Some(Value::Array(items)) = body.get("included") {
+        for (i, item) in items.iter().enumerate() {
+            report.check_modeled::<Included>(item, &format!("included[{i}]"));
+        }
+    }
+    if let Some(links) = body.get("links") {
+        report.check::<Links>(links, "links");
+    }
+    if let Some(meta) = body.get("meta") {
+        report.check::<M>(meta, "meta");
+    }
+    report
+}
+
+struct Fixture {
+    name: &'static str,
+    path: String,
+    params: Value,
+    paid: bool,
+    check: fn(&Value) -> Report,
+}
+
+impl Fixture {
+    fn new(name: &'static str, path: String, params: Value, check: fn(&Value) -> Report) -> Self {
+        Fixture {
+            name,
+            path,
+            params,
+            paid: false,
+            check,
+        }
+    }
+
+    fn paid(self) -> Self {
+        Fixture { paid: true, ..self }
+    }
+}
+
+fn fixtures() -> Vec<Fixture> {
+    let pools = |page: Value| json!({ "page": page, "include": POOL_INCLUDES });
+    vec![
+        Fixture::new(
+            "networks",
+            "/networks".to_string(),
+            json!({ "page": 1 }),
+            check::<Network, Meta>,
+        ),
+        Fixture::new(
+            "network_dexes",
+            "/networks/eth/dexes".to_string(),
+            json!({ "page": 1 }),
+            check::<Dex, Meta>,
+        ),
+        Fixture::new(
+            "trending_pools",
+            "/networks/trending_pools".to_string(),
+            pools(json!(1)),
+            check::<Pool, Meta>,
+        ),
+        Fixture::new(
+            "network_trending_pools",
+            "/networks/eth/trending_pools".to_string(),
+            pools(json!(1)),
+            check::<Pool, Meta>,
+        ),
+        Fixture::new(
+            "network_pools",
+            "/networks/eth/pools".to_string(),
+            json!({ "page": 1, "include": "base_token,quote_token,dex,network" }),
+            check::<Pool, Meta>,
+        ),
+        Fixture::new(
+            "network_pool_address",
+            format!("/networks/eth/pools/{USDC_WETH_POOL}"),
+            json!({ "include": POOL_INCLUDES }),
+            check::<Pool, Meta>,
+        ),
+        Fixture::new(
+            "network_pools_multi_address",
+            format!("/networks/eth/pools/multi/{USDC_WETH_POOL},{PEPE_WETH_POOL}"),
+            json!({ "include": POOL_INCLUDES }),
+            check::<Pool, Meta>,
+        ),
+        Fixture::new(
+            "network_dex_pools",
+            "/networks/eth/dexes/uniswap_v3/pools".to_string(),
+            pools(json!(1)),
+            check::<Pool, Meta>,
+        ),
+        Fixture::new(
+            "network_new_pools",
+            "/networks/eth/new_pools".to_string(),
+            pools(json!(1)),
+            check::<Pool, Meta>,
+        ),
+        Fixture::new(
+            "new_pools",
+            "/networks/new_pools".to_string(),
+            pools(json!(1)),
+            check::<Pool, Meta>,
+        ),
+        Fixture::new(
+            "search_network_pool",
+            "/search/pools".to_string(),
+            json!({ "query": "PEPE", "network": "eth", "page": 1, "include": POOL_INCLUDES }),
+            check::<Pool, Meta>,
+        ),
+        Fixture::new(
+            "megafilter_pools",
+            "/pools/megafilter".to_string(),
+            json!({ "page": 1, "networks": "eth", "include": POOL_INCLUDES }),
+            check::<Pool, Meta>,
+        )
+        .paid(),
+        Fixture::new(
+            "categories",
+            "/categories".to_string(),
+            json!({ "page": 1 }),
+            check::<Category, Meta>,
+        )
+        .paid(),
+        Fixture::new(
+            "category_pools",
+            "/categories/cat-meme/pools".to_string(),
+            pools(json!(1)),
+            check::<Pool, Meta>,
+        )
+        .paid(),
+        Fixture::new(
+            "network_addresses_token_price",
+            format!("/simple/networks/eth/token_price/{USDC},{WETH}"),
+            json!({}),
+            check::<TokenPrice, Meta>,
+        ),
+        Fixture::new(
+            "network_token_pools",
+            format!("/networks/eth/tokens/{USDC}/pools"),
+            pools(json!(1)),
+            check::<Pool, Meta>,
+        ),
+        Fixture::new(
+            "network_token",
+            format!("/networks/eth/tokens/{USDC}"),
+            json!({ "include": "top_pools" }),
+            check::<Token, Meta>,
+        ),
+        Fixture::new(
+            "network_token_multi_address",
+            format!("/networks/eth/tokens/multi/{USDC},{WETH}"),
+            json!({ "include": "top_pools" }),
+            check::<Token, Meta>,
+        ),
+        Fixture::new(
+            "network_tokens_address_info",
+            format!("/networks/eth/tokens/{USDC}/info"),
+            json!({}),
+            check::<TokenInfo, Meta>,
+        ),
+        Fixture::new(
+            "token_info_recently_updated",
+            "/tokens/info_recently_updated".to_string(),
+            json!({ "include": "network" }),
+            check::<TokenInfo, Meta>,
+        ),
+        Fixture::new(
+            "network_token_top_holders",
+            format!("/networks/eth/tokens/{USDC}/top_holders"),
+            json!({ "holders": 1 }),
+            check::<TopHolders, Meta>,
+        )
+        .paid(),
+        Fixture::new(
+            "network_token_top_traders",
+            format!("/networks/eth/traders/{PEPE}"),
+            json!({ "traders": 1, "include_address_label": true }),
+            check::<TopTraders, Meta>,
+        )
+        .paid(),
+        Fixture::new(
+            "network_pool_trades",
+            format!("/networks/eth/pools/{USDC_WETH_POOL}/trades"),
+            json!({}),
+            check::<Trade, Meta>,
+        ),
+        Fixture::new(
+            "network_pool_ohlcv",
+            format!("/networks/eth/pools/{USDC_WETH_POOL}/ohlcv/day"),
+            json!({ "limit": 2 }),
+            check::<OHLCV, Meta>,
+        ),
+        Fixture::new(
+            "network_token_ohlcv",
+            format!("/networks/eth/tokens/{WETH}/ohlcv/day"),
+            json!({ "limit": 2 }),
+            check::<OHLCV, Meta>,
+        )
+        .paid(),
+        Fixture::new(
+            "network_token_trades",
+            format!("/networks/eth/tokens/{WETH}/trades"),
+            json!({}),
+            check::<Trade, Meta>,
+        )
+        .paid(),
+    ]
+}
+
+/// Collect the JSON kinds seen at each field path. Array elements share the `[]` path.
+fn shape(value: &Value, path: String, out: &mut BTreeMap<String, BTreeSet<&'static str>>) {
+    let kind = match value {
+        Value::Null => "null",
+        Value::Bool(_) => "bool",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(items) => {
+            for item in items {
+                shape(item, format!("{path}[]"), out);
+            }
+            "array"
+        }
+        Value::Object(fields) => {
+            for (key, field) in fields {
+                shape(field, format!("{path}.{key}"), out);
+            }
+            "object"
+        }
+    };
+    out.entry(path).or_default().insert(kind);
+}
+
+fn report(name: &str, old: Option<&Value>, new: &Value, check: fn(&Value) -> Report) {
+    println!("{name}:");
+    let report = check(new);
+    if report.failed.is_empty() && report.unmodeled.is_empty() {
+        println!("  deserializes, all fields modeled");
+    }
+    if !report.unmodeled.is_empty() {
+        println!("  unmodeled fields: {:?}", report.unmodeled);
+    }
+    for failed in &report.failed {
+        println!("  FAILED:   {failed}");
+    }
+    let Some(old) = old else {
+        println!("  no existing fixture");
+        return;
+    };
+    let (mut before, mut after) = (BTreeMap::new(), BTreeMap::new());
+    shape(old, String::new(), &mut before);
+    shape(new, String::new(), &mut after);
+    for path in after.keys().filter(|path| !before.contains_key(*path)) {
+        println!("  added:    {path}");
+    }
+    for path in before.keys().filter(|path| !after.contains_key(*path)) {
+        println!("  removed:  {path}");
+    }
+    for (path, kinds) in &after {
+        if let Some(old_kinds) = before.get(path) {
+            if kinds.contains("null") && !old_kinds.contains("null") {
+                println!("  nullable: {path}");
+            }
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let write = args.iter().any(|arg| arg == "--write");
+    let local = args.iter().any(|arg| arg == "--local");
+    let names: Vec<&String> = args.iter().filter(|arg| !arg.starts_with("--")).collect();
+
+    let gt = match env::var("CG_PRO_API_KEY") {
+        Ok(api_key) => GeckoTerminalAPI::with_api_key(&api_key),
+        Err(_) => GeckoTerminalAPI::new(),
+    };
+    let dir: PathBuf = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures");
+
+    for fixture in fixtures() {
+        if !names.is_empty() && !names.iter().any(|name| *name == fixture.name) {
+            continue;
+        }
+        let file = dir.join(format!("{}.json", fixture.name));
+        let old: Option<Value> = fs::read_to_string(&file)
+            .ok()
+            .and_then(|body| serde_json::from_str(&body).ok());
+        if local {
+            match &old {
+                Some(old) => report(fixture.name, Some(old), old, fixture.check),
+                None => println!("{}: no fixture", fixture.name),
+            }
+            continue;
+        }
+        if fixture.paid && !gt.is_authenticated() {
+            println!("{}: skipped, set CG_PRO_API_KEY to refresh", fixture.name);
+            continue;
+        }
+        let new = match gt.get_value(&fixture.path, fixture.params).await {
+            Ok(new) => new,
+            Err(err) => {
+                println!("{}: request failed: {err}", fixture.name);
+                continue;
+            }
+        };
+        report(fixture.name, old.as_ref(), &new, fixture.check);
+        if write {
+            fs::write(&file, serde_json::to_string_pretty(&new)? + "\n")?;
+        }
+    }
+    Ok(())
+}
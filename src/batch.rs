@@ -0,0 +1,300 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::runtime::{Handle, TryCurrentError};
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::{timeout_at, Instant};
+
+use crate::chunking::same_address;
+use crate::limits::MAX_ADDRESSES;
+use crate::types::{pool::Pool, token::Token};
+use crate::{Error, GeckoTerminalAPI};
+
+/// Error returned by [`BatchLoader::load`].
+#[derive(Debug, Clone)]
+pub enum BatchError {
+    /// The batched request failed, the error is shared by every lookup in the batch.
+    Request(Arc<Error>),
+    /// The background task of the loader stopped, e.g. because the runtime shut down.
+    Closed,
+}
+
+impl fmt::Display for BatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BatchError::Request(err) => write!(f, "batched request failed: {err}"),
+            BatchError::Closed => write!(f, "batch loader stopped"),
+        }
+    }
+}
+
+impl std::error::Error for BatchError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BatchError::Request(err) => Some(err.as_ref()),
+            BatchError::Closed => None,
+        }
+    }
+}
+
+struct Request<T> {
+    network: String,
+    address: String,
+    reply: oneshot::Sender<Result<Option<T>, BatchError>>,
+}
+
+/// Collects single-address lookups arriving within a short window and sends them as one
+/// multi-address request per network, handing each caller its own item.
+///
+/// The loader runs a background task on the current tokio runtime, so it must be created
+/// from within one, and can be cloned cheaply to share it between tasks.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// use geckoterminal_rs::batch::BatchLoader;
+/// use geckoterminal_rs::GeckoTerminalAPI;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let gt = Arc::new(GeckoTerminalAPI::new());
+///     let pools = BatchLoader::pools(gt, Duration::from_millis(20))?;
+///     // Both lookups are sent as a single `pools/multi` request.
+///     let (a, b) = tokio::join!(
+///         pools.load("eth", "0x60594a405d53811d3bc4766596efd80fd545a270"),
+///         pools.load("eth", "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640"),
+///     );
+///     println!("{:?} {:?}", a?, b?);
+///     Ok(())
+/// }
+/// ```
+pub struct BatchLoader<T> {
+    sender: mpsc::UnboundedSender<Request<T>>,
+}
+
+impl<T> Clone for BatchLoader<T> {
+    fn clone(&self) -> Self {
+        BatchLoader {
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+impl BatchLoader<Pool> {
+    /// Create a loader that batches pool lookups into `pools/multi` requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to send the batched requests with.
+    /// * `window` - How long to wait for more lookups after the first one of a batch.
+    ///
+    /// Returns an error when called outside a tokio runtime.
+    pub fn pools(client: Arc<GeckoTerminalAPI>, window: Duration) -> Result<Self, TryCurrentError> {
+        BatchLoader::spawn(
+            window,
+            move |network, addresses| {
+                let client = client.clone();
+                async move {
+                    let addresses: Vec<&str> = addresses.iter().map(String::as_str).collect();
+                    let resp = client
+                        .network_pools_multi_address(&network, addresses)
+                        .await?;
+                    Ok(resp.data)
+                }
+            },
+            |pool| &pool.attributes.address,
+        )
+    }
+}
+
+impl BatchLoader<Token> {
+    /// Create a loader that batches token lookups into `tokens/multi` requests.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to send the batched requests with.
+    /// * `window` - How long to wait for more lookups after the first one of a batch.
+    ///
+    /// Returns an error when called outside a tokio runtime.
+    pub fn tokens(
+        client: Arc<GeckoTerminalAPI>,
+        window: Duration,
+    ) -> Result<Self, TryCurrentError> {
+        BatchLoader::spawn(
+            window,
+            move |network, addresses| {
+                let client = client.clone();
+                async move {
+                    let addresses: Vec<&str> = addresses.iter().map(String::as_str).collect();
+                    let resp = client
+                        .network_token_multi_address(&network, addresses)
+                        .await?;
+                    Ok(resp.data)
+                }
+            },
+            |token| &token.attributes.address,
+        )
+    }
+}
+
+impl<T: Clone + Send + 'static> BatchLoader<T> {
+    /// Look up a single item, returning `None` if the API did not return it.
+    ///
+    /// # Arguments
+    ///
+    /// * `network` - The network ID of the network the item is on.
+    /// * `address` - The address of the item to look up.
+    pub async fn load(&self, network: &str, address: &str) -> Result<Option<T>, BatchError> {
+        let (reply, response) = oneshot::channel();
+        let request = Request {
+            network: network.to_string(),
+            address: address.to_string(),
+            reply,
+        };
+        self.sender.send(request).map_err(|_| BatchError::Closed)?;
+        response.await.map_err(|_| BatchError::Closed)?
+    }
+
+    fn spawn<F, Fut>(
+        window: Duration,
+        fetch: F,
+        address_of: fn(&T) -> &str,
+    ) -> Result<Self, TryCurrentError>
+    where
+        F: Fn(String, Vec<String>) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Vec<T>, Error>> + Send + 'static,
+    {
+        let runtime = Handle::try_current()?;
+        let (sender, mut receiver) = mpsc::unbounded_channel::<Request<T>>();
+        runtime.spawn(async move {
+            while let Some(first) = receiver.recv().await {
+                let deadline = Instant::now() + window;
+                let mut batch = vec![first];
+                while batch.len() < MAX_ADDRESSES {
+                    match timeout_at(deadline, receiver.recv()).await {
+                        Ok(Some(request)) => batch.push(request),
+                        _ => break,
+                    }
+                }
+
+                let mut by_network: HashMap<String, Vec<Request<T>>> = HashMap::new();
+                for request in batch {
+                    by_network
+                        .entry(request.network.clone())
+                        .or_default()
+                        .push(request);
+                }
+                for (network, requests) in by_network {
+                    let addresses = requests.iter().map(|r| r.address.clone()).collect();
+                    let fut = fetch(network, addresses);
+                    tokio::spawn(async move {
+                        match fut.await {
+                            Ok(items) => {
+                                for request in requests {
+                                    let item = items
+                                        .iter()
+                                        .find(|item| {
+                                            same_address(address_of(item), &request.address)
+                                        })
+                                        .cloned();
+                                    let _ = request.reply.send(Ok(item));
+                                }
+                            }
+                            Err(err) => {
+                                let err = BatchError::Request(Arc::new(err));
+                                for request in requests {
+                                    let _ = request.reply.send(Err(err.clone()));
+                                }
+                            }
+                        }
+                    });
+                }
+            }
+        });
+        Ok(BatchLoader { sender })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use super::*;
+
+    fn loader(calls: Arc<Mutex<Vec<String>>>) -> BatchLoader<String> {
+        BatchLoader::spawn(
+            Duration::from_millis(20),
+            move |network, addresses| {
+                calls.lock().unwrap().push(network);
+                async move {
+                    Ok(addresses
+                        .into_iter()
+                        .filter(|address| address != "0xmissing")
+                        .map(|address| address.to_uppercase())
+                        .collect())
+                }
+            },
+            |item| item,
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_batch_loader_batches_lookups() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let loader = loader(calls.clone());
+        let (a, b, missing) = tokio::join!(
+            loader.load("eth", "0xa"),
+            loader.load("eth", "0xb"),
+            loader.load("eth", "0xmissing"),
+        );
+        assert_eq!(a.unwrap(), Some("0XA".to_string()));
+        assert_eq!(b.unwrap(), Some("0XB".to_string()));
+        assert_eq!(missing.unwrap(), None);
+        assert_eq!(calls.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_batch_loader_groups_by_network() {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let loader = loader(calls.clone());
+        let (a, b) = tokio::join!(loader.load("eth", "0xa"), loader.load("base", "0xb"));
+        assert!(a.unwrap().is_some() && b.unwrap().is_some());
+        let mut networks = calls.lock().unwrap().clone();
+        networks.sort();
+        assert_eq!(networks, vec!["base", "eth"]);
+    }
+
+    #[tokio::test]
+    async fn test_batch_loader_flushes_full_batches() {
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let counter = fetches.clone();
+        let loader = BatchLoader::spawn(
+            Duration::from_secs(60),
+            move |_, addresses: Vec<String>| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async move { Ok(addresses) }
+            },
+            |item: &String| item,
+        )
+        .unwrap();
+        let addresses: Vec<String> = (0..MAX_ADDRESSES).map(|i| format!("0x{i}")).collect();
+        let lookups = addresses.iter().map(|address| loader.load("eth", address));
+        let results = futures::future::join_all(lookups).await;
+        assert!(results.iter().all(|r| matches!(r, Ok(Some(_)))));
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_batch_loader_outside_runtime() {
+        let client = Arc::new(GeckoTerminalAPI::new());
+        assert!(BatchLoader::pools(client, Duration::from_millis(20)).is_err());
+    }
+}
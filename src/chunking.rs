@@ -0,0 +1,191 @@
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::future::Future;
+
+use futures::stream::{self, StreamExt, TryStreamExt};
+
+use crate::limits::{MAX_ADDRESSES, MAX_CONCURRENT_CHUNKS};
+use crate::types::response::GeckoTerminalResponse;
+use crate::Error;
+
+/// The form addresses are compared in. Hex addresses are case-insensitive, others, such as
+/// base58 Solana addresses, are compared as is.
+fn address_key(address: &str) -> Cow<'_, str> {
+    if address
+        .get(..2)
+        .is_some_and(|prefix| prefix.eq_ignore_ascii_case("0x"))
+    {
+        Cow::Owned(address.to_ascii_lowercase())
+    } else {
+        Cow::Borrowed(address)
+    }
+}
+
+/// Whether two addresses are the same, see [`address_key`].
+pub(crate) fn same_address(a: &str, b: &str) -> bool {
+    address_key(a) == address_key(b)
+}
+
+/// Remove duplicate addresses, keeping the first occurrence of each in input order.
+pub(crate) fn dedupe_addresses<'a>(addresses: &[&'a str]) -> Vec<&'a str> {
+    let mut seen = HashSet::new();
+    addresses
+        .iter()
+        .copied()
+        .filter(|address| seen.insert(address_key(address)))
+        .collect()
+}
+
+/// Split addresses into chunks of at most `MAX_ADDRESSES` and fetch them with bounded
+/// concurrency, returning the responses in chunk order.
+///
+/// # Arguments
+///
+/// * `addresses` - The addresses to split into chunks.
+/// * `fetch` - Fetches a single chunk given its comma-separated addresses.
+pub(crate) async fn fetch_chunks<R, F, Fut>(addresses: &[&str], fetch: F) -> Result<Vec<R>, Error>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<R, Error>>,
+{
+    let chunks: Vec<String> = addresses
+        .chunks(MAX_ADDRESSES)
+        .map(|chunk| chunk.join(","))
+        .collect();
+    if chunks.len() > 1 {
+        log::debug!(
+            "splitting {} addresses into {} requests",
+            addresses.len(),
+            chunks.len()
+        );
+    }
+    stream::iter(chunks)
+        .map(fetch)
+        .buffered(MAX_CONCURRENT_CHUNKS)
+        .try_collect()
+        .await
+}
+
+/// Merge the responses of a chunked list request into one response. Included resources
+/// are de-duplicated, links and meta are taken from the first response.
+pub(crate) fn merge_responses<T, M>(
+    responses: Vec<GeckoTerminalResponse<Vec<T>, M>>,
+) -> GeckoTerminalResponse<Vec<T>, M> {
+    let mut responses = responses.into_iter();
+    let Some(mut merged) = responses.next() else {
+        return GeckoTerminalResponse {
+            data: Vec::new(),
+            links: None,
+            meta: None,
+            included: None,
+        };
+    };
+    for resp in responses {
+        merged.data.extend(resp.data);
+        if let Some(included) = resp.included {
+            let merged_included = merged.included.get_or_insert_with(Vec::new);
+            for item in included {
+                let exists = merged_included
+                    .iter()
+                    .any(|i| i.id() == item.id() && i.type_field() == item.type_field());
+                if !exists {
+                    merged_included.push(item);
+                }
+            }
+        }
+    }
+    merged
+}
+
+/// Sort items by the position of their address in the requested addresses. Items whose
+/// address was not requested keep their relative order at the end.
+pub(crate) fn sort_by_input_order<T>(
+    items: &mut [T],
+    addresses: &[&str],
+    address_of: impl Fn(&T) -> &str,
+) {
+    let keys: Vec<Cow<str>> = addresses.iter().map(|a| address_key(a)).collect();
+    items.sort_by_key(|item| {
+        let address = address_key(address_of(item));
+        keys.iter()
+            .position(|key| *key == address)
+            .unwrap_or(keys.len())
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::types::response::Included;
+    use crate::types::token::TokenSummary;
+
+    #[test]
+    fn test_dedupe_addresses() {
+        let addresses = dedupe_addresses(&["0x2", "0x1", "0x2", "0x3", "0x1"]);
+        assert_eq!(addresses, vec!["0x2", "0x1", "0x3"]);
+
+        let addresses = dedupe_addresses(&["0xABC", "0xabc", "So1A", "So1a"]);
+        assert_eq!(addresses, vec!["0xABC", "So1A", "So1a"]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_chunks() {
+        let owned: Vec<String> = (0..65).map(|i| format!("0x{i}")).collect();
+        let addresses: Vec<&str> = owned.iter().map(String::as_str).collect();
+        let requested = RefCell::new(Vec::new());
+        let sizes = fetch_chunks(&addresses, |chunk| {
+            requested.borrow_mut().push(chunk.clone());
+            async move { Ok(chunk.split(',').count()) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(sizes, vec![30, 30, 5]);
+        assert!(requested.borrow()[1].starts_with("0x30,"));
+    }
+
+    #[test]
+    fn test_merge_responses() {
+        let included = |id: &str| {
+            Included::Token(TokenSummary {
+                id: id.parse().unwrap(),
+                type_field: "token".to_string(),
+                ..Default::default()
+            })
+        };
+        let merged: GeckoTerminalResponse<Vec<i32>> = merge_responses(vec![
+            GeckoTerminalResponse {
+                data: vec![1, 2],
+                links: None,
+                meta: None,
+                included: Some(vec![included("eth_0xa"), included("eth_0xb")]),
+            },
+            GeckoTerminalResponse {
+                data: vec![3],
+                links: None,
+                meta: None,
+                included: Some(vec![included("eth_0xb"), included("eth_0xc")]),
+            },
+        ]);
+        assert_eq!(merged.data, vec![1, 2, 3]);
+        let ids: Vec<String> = merged
+            .included
+            .unwrap()
+            .iter()
+            .map(|i| i.id().to_string())
+            .collect();
+        assert_eq!(ids, vec!["eth_0xa", "eth_0xb", "eth_0xc"]);
+    }
+
+    #[test]
+    fn test_sort_by_input_order() {
+        let mut items = vec!["0xC", "0xd", "0xa", "0xb"];
+        sort_by_input_order(&mut items, &["0xa", "0xb", "0xc"], |item| item);
+        assert_eq!(items, vec!["0xa", "0xb", "0xC", "0xd"]);
+
+        let mut items = vec!["So1a", "So1A"];
+        sort_by_input_order(&mut items, &["So1A", "So1a"], |item| item);
+        assert_eq!(items, vec!["So1A", "So1a"]);
+    }
+}
@@ -0,0 +1,349 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tokio::time::{sleep_until, Instant};
+
+use crate::pagination::has_next_page;
+use crate::types::{dex::Dex, pool::Pool, response::GeckoTerminalResponse};
+use crate::{Error, GeckoTerminalAPI};
+
+/// Error returned by [`Crawler`].
+#[derive(Debug)]
+pub enum CrawlError {
+    /// A request to the API failed, the crawl can be resumed from the last checkpoint.
+    Request(Error),
+    /// The checkpoint file could not be read or written.
+    Io(io::Error),
+    /// The checkpoint file could not be parsed.
+    Checkpoint(serde_json::Error),
+}
+
+impl fmt::Display for CrawlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CrawlError::Request(err) => write!(f, "request failed: {err}"),
+            CrawlError::Io(err) => write!(f, "checkpoint io failed: {err}"),
+            CrawlError::Checkpoint(err) => write!(f, "invalid checkpoint: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for CrawlError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CrawlError::Request(err) => Some(err),
+            CrawlError::Io(err) => Some(err),
+            CrawlError::Checkpoint(err) => Some(err),
+        }
+    }
+}
+
+impl From<Error> for CrawlError {
+    fn from(err: Error) -> Self {
+        CrawlError::Request(err)
+    }
+}
+
+impl From<io::Error> for CrawlError {
+    fn from(err: io::Error) -> Self {
+        CrawlError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for CrawlError {
+    fn from(err: serde_json::Error) -> Self {
+        CrawlError::Checkpoint(err)
+    }
+}
+
+/// Configuration of a [`Crawler`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrawlConfig {
+    /// The network IDs to crawl, in order.
+    pub networks: Vec<String>,
+    /// The file that progress is written to after every page.
+    pub checkpoint_path: PathBuf,
+    /// The minimum time between two requests, defaults to 2 seconds (30 calls per minute).
+    pub request_interval: Duration,
+}
+
+impl CrawlConfig {
+    pub fn new(networks: &[&str], checkpoint_path: impl Into<PathBuf>) -> Self {
+        CrawlConfig {
+            networks: networks.iter().map(|n| n.to_string()).collect(),
+            checkpoint_path: checkpoint_path.into(),
+            request_interval: Duration::from_secs(2),
+        }
+    }
+}
+
+/// The part of a network that is being crawled.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    /// Paging through the dexes of the network.
+    #[default]
+    Dexes,
+    /// Paging through the pools of each dex of the network.
+    DexPools,
+    /// Paging through the top pools of the network.
+    Pools,
+}
+
+/// Progress of a crawl, written to the checkpoint file after every page.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub networks: Vec<String>,
+    pub network_index: usize,
+    pub stage: Stage,
+    pub dexes: Vec<String>,
+    pub dex_index: usize,
+    pub page: i32,
+    pub seen_pools: BTreeSet<String>,
+}
+
+impl Checkpoint {
+    fn new(networks: &[String]) -> Self {
+        Checkpoint {
+            networks: networks.to_vec(),
+            page: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Load a checkpoint from a file, or `None` if the file does not exist.
+    pub fn load(path: &Path) -> Result<Option<Checkpoint>, CrawlError> {
+        match fs::read(path) {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Write the checkpoint to a file, replacing it atomically.
+    pub fn save(&self, path: &Path) -> Result<(), CrawlError> {
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_vec(self)?)?;
+        fs::rename(&tmp, path)?;
+        Ok(())
+    }
+
+    /// Whether every network has been crawled.
+    pub fn is_finished(&self) -> bool {
+        self.network_index >= self.networks.len()
+    }
+
+    /// Move to the position after the page that was just crawled.
+    fn advance(&mut self, has_next_page: bool) {
+        if has_next_page {
+            self.page += 1;
+            return;
+        }
+        self.page = 1;
+        match self.stage {
+            Stage::Dexes => {
+                self.stage = Stage::DexPools;
+                self.dex_index = 0;
+            }
+            Stage::DexPools => {
+                self.dex_index += 1;
+                if self.dex_index >= self.dexes.len() {
+                    self.stage = Stage::Pools;
+                }
+            }
+            Stage::Pools => {
+                self.network_index += 1;
+                self.stage = Stage::Dexes;
+                self.dexes.clear();
+                self.dex_index = 0;
+            }
+        }
+    }
+}
+
+/// An item found by a [`Crawler`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum CrawlItem {
+    Dex { network: String, dex: Dex },
+    Pool { network: String, pool: Box<Pool> },
+}
+
+/// Walks the dexes, dex pools and top pools of a set of networks, writing a checkpoint
+/// after every page so an interrupted crawl resumes where it stopped.
+///
+/// Pools are de-duplicated across the whole crawl. A pool found on a page that was
+/// crawled but not yet checkpointed can be emitted again after resuming.
+///
+/// # Examples
+///
+/// ```no_run
+/// use geckoterminal_rs::crawl::{CrawlConfig, CrawlItem, Crawler};
+/// use geckoterminal_rs::GeckoTerminalAPI;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let gt = GeckoTerminalAPI::new();
+///     let config = CrawlConfig::new(&["eth", "base"], "crawl.json");
+///     let mut crawler = Crawler::new(&gt, config)?;
+///     crawler
+///         .run(|item| {
+///             if let CrawlItem::Pool { network, pool } = item {
+///                 println!("{network}: {}", pool.attributes.name);
+///             }
+///         })
+///         .await?;
+///     Ok(())
+/// }
+/// ```
+pub struct Crawler<'a> {
+    client: &'a GeckoTerminalAPI,
+    config: CrawlConfig,
+    checkpoint: Checkpoint,
+    next_request: Instant,
+}
+
+impl<'a> Crawler<'a> {
+    /// Create a crawler, resuming from the checkpoint file if it exists and was written
+    /// for the same networks.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - The client to send requests with.
+    /// * `config` - The networks to crawl and where to write checkpoints.
+    pub fn new(client: &'a GeckoTerminalAPI, config: CrawlConfig) -> Result<Self, CrawlError> {
+        let checkpoint = match Checkpoint::load(&config.checkpoint_path)? {
+            Some(checkpoint) if checkpoint.networks == config.networks => checkpoint,
+            Some(_) => {
+                log::warn!("checkpoint was written for other networks, starting over");
+                Checkpoint::new(&config.networks)
+            }
+            None => Checkpoint::new(&config.networks),
+        };
+        Ok(Crawler {
+            client,
+            config,
+            checkpoint,
+            next_request: Instant::now(),
+        })
+    }
+
+    /// The current progress of the crawl.
+    pub fn checkpoint(&self) -> &Checkpoint {
+        &self.checkpoint
+    }
+
+    /// Crawl until every network is done, passing each new dex and pool to `emit`.
+    ///
+    /// On error the checkpoint points at the page that failed, so calling `run` again
+    /// retries it.
+    pub async fn run(&mut self, mut emit: impl FnMut(CrawlItem)) -> Result<(), CrawlError> {
+        while !self.checkpoint.is_finished() {
+            let network = self.checkpoint.networks[self.checkpoint.network_index].clone();
+            let page = Some(self.checkpoint.page);
+            self.throttle().await;
+            let has_next = match self.checkpoint.stage {
+                Stage::Dexes => {
+                    let resp = self.client.network_dexes(&network, page).await?;
+                    let has_next = has_next_page(&resp, self.checkpoint.page);
+                    for dex in resp.data {
+                        self.checkpoint.dexes.push(dex.id.to_string());
+                        emit(CrawlItem::Dex {
+                            network: network.clone(),
+                            dex,
+                        });
+                    }
+                    has_next
+                }
+                Stage::DexPools => match self.checkpoint.dexes.get(self.checkpoint.dex_index) {
+                    Some(dex) => {
+                        let resp = self.client.network_dex_pools(&network, dex, page).await?;
+                        self.emit_pools(&network, resp, &mut emit)
+                    }
+                    None => false,
+                },
+                Stage::Pools => {
+                    let resp = self.client.network_pools(&network, page).await?;
+                    self.emit_pools(&network, resp, &mut emit)
+                }
+            };
+            self.checkpoint.advance(has_next);
+            self.checkpoint.save(&self.config.checkpoint_path)?;
+        }
+        Ok(())
+    }
+
+    fn emit_pools(
+        &mut self,
+        network: &str,
+        resp: GeckoTerminalResponse<Vec<Pool>>,
+        emit: &mut impl FnMut(CrawlItem),
+    ) -> bool {
+        let has_next = has_next_page(&resp, self.checkpoint.page);
+        for pool in resp.data {
+            if self.checkpoint.seen_pools.insert(pool.id.to_string()) {
+                emit(CrawlItem::Pool {
+                    network: network.to_string(),
+                    pool: Box::new(pool),
+                });
+            }
+        }
+        has_next
+    }
+
+    async fn throttle(&mut self) {
+        sleep_until(self.next_request).await;
+        self.next_request = Instant::now() + self.config.request_interval;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checkpoint_advance() {
+        let mut checkpoint = Checkpoint::new(&["eth".to_string()]);
+        checkpoint.advance(true);
+        assert_eq!((checkpoint.stage, checkpoint.page), (Stage::Dexes, 2));
+
+        checkpoint.dexes = vec!["uniswap_v2".to_string(), "sushiswap".to_string()];
+        checkpoint.advance(false);
+        assert_eq!((checkpoint.stage, checkpoint.page), (Stage::DexPools, 1));
+        checkpoint.advance(true);
+        checkpoint.advance(false);
+        assert_eq!((checkpoint.dex_index, checkpoint.page), (1, 1));
+        checkpoint.advance(false);
+        assert_eq!(checkpoint.stage, Stage::Pools);
+
+        checkpoint.advance(false);
+        assert!(checkpoint.is_finished());
+        assert_eq!(checkpoint.stage, Stage::Dexes);
+        assert!(checkpoint.dexes.is_empty());
+    }
+
+    #[test]
+    fn test_checkpoint_save_load() {
+        let path = std::env::temp_dir().join(format!("gt_crawl_{}.json", std::process::id()));
+        assert_eq!(Checkpoint::load(&path).unwrap(), None);
+
+        let mut checkpoint = Checkpoint::new(&["eth".to_string(), "base".to_string()]);
+        checkpoint.stage = Stage::DexPools;
+        checkpoint.dexes = vec!["uniswap_v3".to_string()];
+        checkpoint.seen_pools.insert("eth_0x1".to_string());
+        checkpoint.save(&path).unwrap();
+        assert_eq!(Checkpoint::load(&path).unwrap(), Some(checkpoint.clone()));
+
+        let client = GeckoTerminalAPI::new();
+        let crawler = Crawler::new(&client, CrawlConfig::new(&["eth", "base"], &path)).unwrap();
+        assert_eq!(crawler.checkpoint(), &checkpoint);
+        let crawler = Crawler::new(&client, CrawlConfig::new(&["solana"], &path)).unwrap();
+        assert_eq!(crawler.checkpoint().stage, Stage::Dexes);
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+
+use crate::types::id::ParseIdError;
+
+/// Error returned by the methods of [`GeckoTerminalAPI`](crate::GeckoTerminalAPI).
+#[derive(Debug)]
+pub enum Error {
+    /// The request failed or the API responded with an error status.
+    Request(reqwest::Error),
+    /// The response body does not match the expected type.
+    Json(serde_json::Error),
+    /// The endpoint is only available on the paid API and the client has no API key, see
+    /// [`GeckoTerminalAPI::with_api_key`](crate::GeckoTerminalAPI::with_api_key). No request
+    /// was sent.
+    MissingApiKey,
+    /// A typed pool or token ID has no network or no address, e.g. one that was not in the
+    /// `{network}_{address}` form when deserialized. No request was sent.
+    InvalidId(ParseIdError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Request(err) => write!(f, "request failed: {err}"),
+            Error::Json(err) => write!(f, "invalid response body: {err}"),
+            Error::MissingApiKey => write!(f, "endpoint requires an API key"),
+            Error::InvalidId(err) => err.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Request(err) => Some(err),
+            Error::Json(err) => Some(err),
+            Error::MissingApiKey => None,
+            Error::InvalidId(err) => Some(err),
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Request(err)
+    }
+}
+
+impl From<ParseIdError> for Error {
+    fn from(err: ParseIdError) -> Self {
+        Error::InvalidId(err)
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Json(err)
+    }
+}
@@ -0,0 +1,93 @@
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use crate::types::{
+    holder::TopHolders, ohlcv::OHLCV, pool::Pool, response::GeckoTerminalResponse,
+    simple::TokenPrice, token::Token, token_info::TokenInfo, trader::TopTraders,
+};
+
+/// The `data` of a response. Lists skip the items that do not deserialize, logging a warning
+/// for each; single resources deserialize as usual.
+pub(crate) trait LenientData: DeserializeOwned {
+    fn from_value_lenient(value: Value) -> Result<Self, serde_json::Error> {
+        serde_json::from_value(value)
+    }
+}
+
+impl<T: DeserializeOwned> LenientData for Vec<T> {
+    fn from_value_lenient(value: Value) -> Result<Self, serde_json::Error> {
+        match value {
+            Value::Array(items) => Ok(skip_malformed(items, "item")),
+            value => serde_json::from_value(value),
+        }
+    }
+}
+
+impl LenientData for Pool {}
+impl LenientData for Token {}
+impl LenientData for TokenInfo {}
+impl LenientData for TokenPrice {}
+impl LenientData for TopHolders {}
+impl LenientData for TopTraders {}
+impl LenientData for OHLCV {}
+
+/// Deserialize each item, dropping the ones that fail.
+fn skip_malformed<T: DeserializeOwned>(items: Vec<Value>, kind: &str) -> Vec<T> {
+    items
+        .into_iter()
+        .filter_map(|item| {
+            let id = item["id"].as_str().unwrap_or_default().to_string();
+            serde_json::from_value(item)
+                .map_err(|err| log::warn!("Skipping malformed {kind} {id:?}: {err}"))
+                .ok()
+        })
+        .collect()
+}
+
+/// Deserialize a response body, skipping the items of its `data` and `included` lists that do
+/// not deserialize.
+pub(crate) fn from_value_lenient<T: LenientData, M: DeserializeOwned>(
+    mut body: Value,
+) -> Result<GeckoTerminalResponse<T, M>, serde_json::Error> {
+    let mut take = |key: &str| body.get_mut(key).map(Value::take).unwrap_or_default();
+    let data = T::from_value_lenient(take("data"))?;
+    let included = match take("included") {
+        Value::Array(items) => Some(skip_malformed(items, "included item")),
+        value => serde_json::from_value(value)?,
+    };
+    Ok(GeckoTerminalResponse {
+        data,
+        links: serde_json::from_value(take("links"))?,
+        meta: serde_json::from_value(take("meta"))?,
+        included,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::types::dex::Dex;
+
+    #[test]
+    fn test_from_value_lenient() {
+        let body = json!({
+            "data": [
+                {"id": "uniswap_v3", "type": "dex", "attributes": {"name": "Uniswap V3"}},
+                {"id": "broken", "type": "dex", "attributes": {"name": null}},
+            ],
+            "included": [
+                {"id": "eth", "type": "network", "attributes": {}},
+                {"id": "eth_0xa", "type": "holder", "attributes": {}},
+            ]
+        });
+        let resp: GeckoTerminalResponse<Vec<Dex>> = from_value_lenient(body).unwrap();
+        assert_eq!(resp.data.len(), 1);
+        assert_eq!(resp.data[0].id, "uniswap_v3");
+        let included = resp.included.unwrap();
+        assert_eq!(included.len(), 1);
+        assert_eq!(included[0].id(), "eth_0xa");
+        assert!(resp.links.is_none());
+    }
+}
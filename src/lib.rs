@@ -1,16 +1,47 @@
+use bytes::Bytes;
+use futures::stream::Stream;
 use serde_json::{json, Value};
 use std::time::{SystemTime, UNIX_EPOCH};
 
+pub use crate::error::Error;
+
+use crate::chunking::{dedupe_addresses, fetch_chunks, merge_responses, sort_by_input_order};
+use crate::lenient::{from_value_lenient, LenientData};
+use crate::limits::OHLCV_LIMIT;
+use crate::options::{
+    CategoryPoolSort, CategorySort, PoolFilter, TokenTradeOptions, TradeOptions, TraderSort,
+};
+use crate::pagination::{backfill_ohlcv, paginate};
 use crate::types::{
-    dex::Dex, network::Network, ohlcv::OHLCV, pool::Pool, response::GeckoTerminalResponse,
-    simple::TokenPrice, token::Token, token_info::TokenInfo, trade::Trade,
+    category::Category,
+    dex::Dex,
+    holder::TopHolders,
+    id::{PoolId, TokenId},
+    network::Network,
+    ohlcv::OHLCV,
+    pool::Pool,
+    response::GeckoTerminalResponse,
+    simple::TokenPrice,
+    token::Token,
+    token_info::TokenInfo,
+    trade::Trade,
+    trader::TopTraders,
 };
 use crate::validation::{
-    check_addresses, check_aggregate, check_currency, check_ohlcv_limit, check_page,
-    check_timeframe, check_token,
+    check_aggregate, check_api_key, check_currency, check_ohlcv_limit, check_page, check_timeframe,
+    check_token, check_trade_order,
 };
 
+pub mod batch;
+mod chunking;
+pub mod crawl;
+mod error;
+mod lenient;
 pub mod limits;
+pub mod options;
+pub mod pagination;
+#[cfg(feature = "schema")]
+pub mod schema;
 pub mod types;
 pub mod validation;
 
@@ -18,6 +49,8 @@ pub struct GeckoTerminalAPI {
     client: reqwest::Client,
     base_url: String,
     accept_header: String,
+    api_key: Option<String>,
+    skip_malformed_items: bool,
 }
 
 impl Default for GeckoTerminalAPI {
@@ -26,6 +59,8 @@ impl Default for GeckoTerminalAPI {
             client: reqwest::Client::new(),
             base_url: "https://api.geckoterminal.com/api/v2".to_string(),
             accept_header: "application/json".to_string(),
+            api_key: None,
+            skip_malformed_items: false,
         }
     }
 }
@@ -49,47 +84,183 @@ impl GeckoTerminalAPI {
         GeckoTerminalAPI::default()
     }
 
+    /// Create a new authenticated `GeckoTerminalAPI` client for the paid onchain API.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - The CoinGecko Pro API key to send with each request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geckoterminal_rs::GeckoTerminalAPI;
+    ///
+    /// let gt = GeckoTerminalAPI::with_api_key("CG-xxxxxxxxxxxxxxxxxxxxxxxx");
+    /// assert!(gt.is_authenticated());
+    /// ```
+    #[must_use]
+    pub fn with_api_key(api_key: &str) -> GeckoTerminalAPI {
+        GeckoTerminalAPI {
+            base_url: "https://pro-api.coingecko.com/api/v3/onchain".to_string(),
+            api_key: Some(api_key.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Skip items of a response list that fail to deserialize, logging a warning for each,
+    /// instead of failing the whole response. Off by default.
+    ///
+    /// # Arguments
+    ///
+    /// * `skip` - Whether to skip malformed items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geckoterminal_rs::GeckoTerminalAPI;
+    ///
+    /// let gt = GeckoTerminalAPI::new().skip_malformed_items(true);
+    /// ```
+    #[must_use]
+    pub fn skip_malformed_items(mut self, skip: bool) -> GeckoTerminalAPI {
+        self.skip_malformed_items = skip;
+        self
+    }
+
+    /// Whether the client was created with an API key and can use paid endpoints.
+    #[must_use]
+    pub fn is_authenticated(&self) -> bool {
+        self.api_key.is_some()
+    }
+
     /// Make a GET request to the `GeckoTerminalAPI`.
     ///
     /// # Arguments
     ///
     /// * `path` - The path to make the GET request to.
     /// * `params` - The query parameters to include in the GET request.
-    async fn get(&self, path: String, params: Value) -> Result<reqwest::Response, reqwest::Error> {
+    async fn get(&self, path: String, params: Value) -> Result<reqwest::Response, Error> {
         let url = format!("{}{}", self.base_url, path);
-        let resp = self
+        let mut req = self
             .client
             .get(&url)
             .query(&params)
-            .header("Accept", &self.accept_header)
-            .send()
-            .await;
+            .header("Accept", &self.accept_header);
+        if let Some(api_key) = &self.api_key {
+            req = req.header("x-cg-pro-api-key", api_key);
+        }
+        let resp = req.send().await;
 
         match resp {
             Ok(resp) => match resp.error_for_status() {
                 Ok(r) => Ok(r),
                 Err(err) => {
                     log::error!("Error: {}", err);
-                    Err(err)
+                    Err(err.into())
                 }
             },
             Err(err) => {
                 log::error!("Error: {}", err);
-                Err(err)
+                Err(err.into())
             }
         }
     }
 
+    /// Deserialize the body of a response.
+    ///
+    /// # Arguments
+    ///
+    /// * `resp` - The `reqwest::Response` to deserialize.
+    async fn decode<T: serde::de::DeserializeOwned>(resp: reqwest::Response) -> Result<T, Error> {
+        let body = resp.bytes().await?;
+        Ok(serde_json::from_slice(&body)?)
+    }
+
     /// This function is used to format the response from the `GeckoTerminalAPI`.
     ///
     /// # Arguments
     ///
     /// * `resp` - The `reqwest::Response` to format.
-    async fn format_response<T: serde::de::DeserializeOwned>(
+    async fn format_response<T: LenientData>(
         &self,
         resp: reqwest::Response,
-    ) -> Result<GeckoTerminalResponse<T>, reqwest::Error> {
-        resp.json::<GeckoTerminalResponse<T>>().await
+    ) -> Result<GeckoTerminalResponse<T>, Error> {
+        if !self.skip_malformed_items {
+            return Self::decode(resp).await;
+        }
+        Ok(from_value_lenient(Self::decode(resp).await?)?)
+    }
+
+    /// Make a GET request to any endpoint of the API and deserialize the response body, for
+    /// endpoints that are not yet wrapped by this client. The request uses the client's base
+    /// URL, headers and error handling.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the endpoint relative to the base URL, e.g. "/networks".
+    /// * `params` - The query parameters to include in the GET request.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use geckoterminal_rs::types::{network::Network, response::GeckoTerminalResponse};
+    /// use geckoterminal_rs::GeckoTerminalAPI;
+    /// use serde_json::json;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let gt = GeckoTerminalAPI::new();
+    ///     let networks: GeckoTerminalResponse<Vec<Network>> =
+    ///         gt.get_json("/networks", json!({ "page": 2 })).await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_json<T: serde::de::DeserializeOwned>(
+        &self,
+        path: &str,
+        params: Value,
+    ) -> Result<T, Error> {
+        let resp = self.get(path.to_string(), params).await?;
+        Self::decode(resp).await
+    }
+
+    /// Make a GET request to any endpoint of the API and return the untyped JSON body.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the endpoint relative to the base URL, e.g. "/networks".
+    /// * `params` - The query parameters to include in the GET request.
+    pub async fn get_value(&self, path: &str, params: Value) -> Result<Value, Error> {
+        self.get_json::<Value>(path, params).await
+    }
+
+    /// Make a GET request to any endpoint of the API and return the raw response body, e.g.
+    /// to deserialize it into the borrowed models of [`types::borrowed`].
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the endpoint relative to the base URL, e.g. "/networks".
+    /// * `params` - The query parameters to include in the GET request.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use geckoterminal_rs::types::borrowed::{ResponseRef, TradeRef};
+    /// use geckoterminal_rs::GeckoTerminalAPI;
+    /// use serde_json::json;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let gt = GeckoTerminalAPI::new();
+    ///     let path = "/networks/eth/pools/0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640/trades";
+    ///     let body = gt.get_bytes(path, json!({})).await?;
+    ///     let trades: ResponseRef<Vec<TradeRef>> = serde_json::from_slice(&body)?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub async fn get_bytes(&self, path: &str, params: Value) -> Result<Bytes, Error> {
+        let resp = self.get(path.to_string(), params).await?;
+        Ok(resp.bytes().await?)
     }
 
     /// Get all supported networks along with their network ID.
@@ -100,7 +271,7 @@ impl GeckoTerminalAPI {
     pub async fn networks(
         &self,
         page: Option<i32>,
-    ) -> Result<GeckoTerminalResponse<Vec<Network>>, reqwest::Error> {
+    ) -> Result<GeckoTerminalResponse<Vec<Network>>, Error> {
         let page = page.unwrap_or(1);
         check_page(&page);
         let path = "/networks".to_string();
@@ -109,6 +280,11 @@ impl GeckoTerminalAPI {
         self.format_response::<Vec<Network>>(resp).await
     }
 
+    /// Stream all supported networks, fetching pages lazily up to `MAX_PAGE`.
+    pub fn networks_stream(&self) -> impl Stream<Item = Result<Network, Error>> + '_ {
+        paginate(move |page| self.networks(Some(page)))
+    }
+
     /// Get all supported dexes along with their dex ID.
     ///
     /// # Arguments
@@ -119,7 +295,7 @@ impl GeckoTerminalAPI {
         &self,
         network: &str,
         page: Option<i32>,
-    ) -> Result<GeckoTerminalResponse<Vec<Dex>>, reqwest::Error> {
+    ) -> Result<GeckoTerminalResponse<Vec<Dex>>, Error> {
         let page = page.unwrap_or(1);
         check_page(&page);
         let path = format!("/networks/{network}/dexes");
@@ -128,6 +304,18 @@ impl GeckoTerminalAPI {
         self.format_response::<Vec<Dex>>(resp).await
     }
 
+    /// Stream all supported dexes on a network, fetching pages lazily up to `MAX_PAGE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `network` - The network ID of the network to get dexes for.
+    pub fn network_dexes_stream<'a>(
+        &'a self,
+        network: &'a str,
+    ) -> impl Stream<Item = Result<Dex, Error>> + 'a {
+        paginate(move |page| self.network_dexes(network, Some(page)))
+    }
+
     /// Get all trending pools on all networks.
     ///
     /// # Arguments
@@ -136,7 +324,7 @@ impl GeckoTerminalAPI {
     pub async fn trending_pools(
         &self,
         page: Option<i32>,
-    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, reqwest::Error> {
+    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, Error> {
         let page = page.unwrap_or(1);
         check_page(&page);
         let path = "/networks/trending_pools".to_string();
@@ -146,6 +334,11 @@ impl GeckoTerminalAPI {
         self.format_response::<Vec<Pool>>(resp).await
     }
 
+    /// Stream trending pools on all networks, fetching pages lazily up to `MAX_PAGE`.
+    pub fn trending_pools_stream(&self) -> impl Stream<Item = Result<Pool, Error>> + '_ {
+        paginate(move |page| self.trending_pools(Some(page)))
+    }
+
     /// Get all trending pools on a specific network.
     ///
     /// # Arguments
@@ -156,7 +349,7 @@ impl GeckoTerminalAPI {
         &self,
         network: &str,
         page: Option<i32>,
-    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, reqwest::Error> {
+    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, Error> {
         let page = page.unwrap_or(1);
         check_page(&page);
         let path = format!("/networks/{network}/trending_pools");
@@ -166,6 +359,18 @@ impl GeckoTerminalAPI {
         self.format_response::<Vec<Pool>>(resp).await
     }
 
+    /// Stream trending pools on a specific network, fetching pages lazily up to `MAX_PAGE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `network` - The network ID of the network to get trending pools for.
+    pub fn network_trending_pools_stream<'a>(
+        &'a self,
+        network: &'a str,
+    ) -> impl Stream<Item = Result<Pool, Error>> + 'a {
+        paginate(move |page| self.network_trending_pools(network, Some(page)))
+    }
+
     /// Get a specific pool on a specific network.
     ///
     /// # Arguments
@@ -176,7 +381,7 @@ impl GeckoTerminalAPI {
         &self,
         network: &str,
         address: &str,
-    ) -> Result<GeckoTerminalResponse<Pool>, reqwest::Error> {
+    ) -> Result<GeckoTerminalResponse<Pool>, Error> {
         let path = format!("/networks/{network}/pools/{address}");
         let include_str = "base_token,quote_token,dex";
         let params = json!({ "include": include_str });
@@ -184,8 +389,22 @@ impl GeckoTerminalAPI {
         self.format_response::<Pool>(resp).await
     }
 
+    /// Get a specific pool by its ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `pool` - The ID of the pool to get, e.g. taken from `Pool::id` of another response.
+    pub async fn pool(&self, pool: &PoolId) -> Result<GeckoTerminalResponse<Pool>, Error> {
+        pool.validate()?;
+        self.network_pool_address(&pool.network, &pool.address)
+            .await
+    }
+
     /// Get multiple pools on a specific network.
     ///
+    /// Duplicate addresses are removed, and more than `MAX_ADDRESSES` addresses are split into
+    /// several requests whose results are merged, keeping the pools in input order.
+    ///
     /// # Arguments
     ///
     /// * `network` - The network ID of the network to get the pools for.
@@ -194,13 +413,19 @@ impl GeckoTerminalAPI {
         &self,
         network: &str,
         addresses: Vec<&str>,
-    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, reqwest::Error> {
-        check_addresses(&addresses);
-        let path = format!("/networks/{network}/pools/multi/{}", addresses.join(","));
-        let include_str = "base_token,quote_token,dex";
-        let params = json!({ "include": include_str });
-        let resp = self.get(path, params).await?;
-        self.format_response::<Vec<Pool>>(resp).await
+    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, Error> {
+        let addresses = dedupe_addresses(&addresses);
+        let responses = fetch_chunks(&addresses, |chunk| async move {
+            let path = format!("/networks/{network}/pools/multi/{chunk}");
+            let include_str = "base_token,quote_token,dex";
+            let params = json!({ "include": include_str });
+            let resp = self.get(path, params).await?;
+            self.format_response::<Vec<Pool>>(resp).await
+        })
+        .await?;
+        let mut resp = merge_responses(responses);
+        sort_by_input_order(&mut resp.data, &addresses, |pool| &pool.attributes.address);
+        Ok(resp)
     }
 
     /// Get all pools on a specific network.
@@ -213,7 +438,7 @@ impl GeckoTerminalAPI {
         &self,
         network: &str,
         page: Option<i32>,
-    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, reqwest::Error> {
+    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, Error> {
         let page = page.unwrap_or(1);
         check_page(&page);
         let path = format!("/networks/{network}/pools");
@@ -223,6 +448,32 @@ impl GeckoTerminalAPI {
         self.format_response::<Vec<Pool>>(resp).await
     }
 
+    /// Stream all pools on a specific network, fetching pages lazily up to `MAX_PAGE`.
+    ///
+    /// # Arguments
+    ///
+    /// * `network` - The network ID of the network to get the pools for.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    /// use geckoterminal_rs::GeckoTerminalAPI;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let gt = GeckoTerminalAPI::new();
+    ///     let pools: Vec<_> = gt.network_pools_stream("eth").try_collect().await?;
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn network_pools_stream<'a>(
+        &'a self,
+        network: &'a str,
+    ) -> impl Stream<Item = Result<Pool, Error>> + 'a {
+        paginate(move |page| self.network_pools(network, Some(page)))
+    }
+
     /// Get top pools on a network's dex.
     ///
     /// # Arguments
@@ -234,7 +485,7 @@ impl GeckoTerminalAPI {
         network: &str,
         dex: &str,
         page: Option<i32>,
-    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, reqwest::Error> {
+    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, Error> {
         let page = page.unwrap_or(1);
         check_page(&page);
         let path = format!("/networks/{network}/dexes/{dex}/pools");
@@ -244,6 +495,19 @@ impl GeckoTerminalAPI {
         self.format_response::<Vec<Pool>>(resp).await
     }
 
+    /// Stream top pools on a network's dex, fetching pages lazily up to `MAX_PAGE`.
+    ///
+    /// # Arguments
+    /// * `network` - The network ID of the network to get the pools for.
+    /// * `dex` - The dex ID of the dex to get the pools for.
+    pub fn network_dex_pools_stream<'a>(
+        &'a self,
+        network: &'a str,
+        dex: &'a str,
+    ) -> impl Stream<Item = Result<Pool, Error>> + 'a {
+        paginate(move |page| self.network_dex_pools(network, dex, Some(page)))
+    }
+
     /// Get new pools on a network.
     ///
     /// # Arguments
@@ -253,7 +517,7 @@ impl GeckoTerminalAPI {
         &self,
         network: &str,
         page: Option<i32>,
-    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, reqwest::Error> {
+    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, Error> {
         let page = page.unwrap_or(1);
         check_page(&page);
         let path = format!("/networks/{network}/new_pools");
@@ -263,6 +527,17 @@ impl GeckoTerminalAPI {
         self.format_response::<Vec<Pool>>(resp).await
     }
 
+    /// Stream new pools on a network, fetching pages lazily up to `MAX_PAGE`.
+    ///
+    /// # Arguments
+    /// * `network` - The network ID of the network to get the pools for.
+    pub fn network_new_pools_stream<'a>(
+        &'a self,
+        network: &'a str,
+    ) -> impl Stream<Item = Result<Pool, Error>> + 'a {
+        paginate(move |page| self.network_new_pools(network, Some(page)))
+    }
+
     /// Get new pools on all networks.
     ///
     /// # Arguments
@@ -270,7 +545,7 @@ impl GeckoTerminalAPI {
     pub async fn new_pools(
         &self,
         page: Option<i32>,
-    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, reqwest::Error> {
+    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, Error> {
         let page = page.unwrap_or(1);
         check_page(&page);
         let path = "/networks/new_pools".to_string();
@@ -280,6 +555,11 @@ impl GeckoTerminalAPI {
         self.format_response::<Vec<Pool>>(resp).await
     }
 
+    /// Stream new pools on all networks, fetching pages lazily up to `MAX_PAGE`.
+    pub fn new_pools_stream(&self) -> impl Stream<Item = Result<Pool, Error>> + '_ {
+        paginate(move |page| self.new_pools(Some(page)))
+    }
+
     /// Search for a pool on a networks.
     ///
     /// # Arguments
@@ -291,7 +571,7 @@ impl GeckoTerminalAPI {
         query: &str,
         network: &str,
         page: Option<i32>,
-    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, reqwest::Error> {
+    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, Error> {
         let page = page.unwrap_or(1);
         check_page(&page);
         let path = "/search/pools".to_string();
@@ -302,8 +582,131 @@ impl GeckoTerminalAPI {
         self.format_response::<Vec<Pool>>(resp).await
     }
 
+    /// Stream search results for a pool on a network, fetching pages lazily up to `MAX_PAGE`.
+    ///
+    /// # Arguments
+    /// * `query` - The query string to search for, can be pool address, token address, or token symbol.
+    /// * `network` - The network ID of the network to search on.
+    pub fn search_network_pool_stream<'a>(
+        &'a self,
+        query: &'a str,
+        network: &'a str,
+    ) -> impl Stream<Item = Result<Pool, Error>> + 'a {
+        paginate(move |page| self.search_network_pool(query, network, Some(page)))
+    }
+
+    /// Get pools across networks matching a set of filters, such as reserve, FDV, volume,
+    /// transaction counts, pool age and checks.
+    /// Requires an authenticated client, see [`GeckoTerminalAPI::with_api_key`].
+    ///
+    /// # Arguments
+    /// * `filter` - The filters to apply, see [`PoolFilter`].
+    pub async fn megafilter_pools(
+        &self,
+        filter: &PoolFilter,
+    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, Error> {
+        check_api_key(&self.api_key)?;
+        check_page(&filter.current_page());
+        let path = "/pools/megafilter".to_string();
+        let include_str = "base_token,quote_token,dex,network";
+        let mut params = filter.to_params();
+        params["include"] = json!(include_str);
+        let resp = self.get(path, params).await?;
+        self.format_response::<Vec<Pool>>(resp).await
+    }
+
+    /// Stream pools matching a set of filters, fetching pages lazily up to `MAX_PAGE`. The
+    /// page set on the filter is ignored.
+    /// Requires an authenticated client, see [`GeckoTerminalAPI::with_api_key`].
+    ///
+    /// # Arguments
+    /// * `filter` - The filters to apply, see [`PoolFilter`].
+    pub fn megafilter_pools_stream<'a>(
+        &'a self,
+        filter: &'a PoolFilter,
+    ) -> impl Stream<Item = Result<Pool, Error>> + 'a {
+        paginate(move |page| {
+            let filter = filter.clone().page(page);
+            async move { self.megafilter_pools(&filter).await }
+        })
+    }
+
+    /// Get all pool categories, such as meme, AI or gaming.
+    /// Requires an authenticated client, see [`GeckoTerminalAPI::with_api_key`].
+    ///
+    /// # Arguments
+    /// * `page` - The page number of the results to return.
+    /// * `sort` - The sort order of the categories. This is optional and defaults to `H6VolumePercentageDesc`.
+    pub async fn categories(
+        &self,
+        page: Option<i32>,
+        sort: Option<CategorySort>,
+    ) -> Result<GeckoTerminalResponse<Vec<Category>>, Error> {
+        check_api_key(&self.api_key)?;
+        let page = page.unwrap_or(1);
+        check_page(&page);
+        let sort = sort.unwrap_or(CategorySort::H6VolumePercentageDesc);
+        let path = "/categories".to_string();
+        let params = json!({ "page": page, "sort": sort.as_str() });
+        let resp = self.get(path, params).await?;
+        self.format_response::<Vec<Category>>(resp).await
+    }
+
+    /// Stream all pool categories, fetching pages lazily up to `MAX_PAGE`.
+    /// Requires an authenticated client, see [`GeckoTerminalAPI::with_api_key`].
+    ///
+    /// # Arguments
+    /// * `sort` - The sort order of the categories. This is optional and defaults to `H6VolumePercentageDesc`.
+    pub fn categories_stream(
+        &self,
+        sort: Option<CategorySort>,
+    ) -> impl Stream<Item = Result<Category, Error>> + '_ {
+        paginate(move |page| self.categories(Some(page), sort))
+    }
+
+    /// Get pools in a category.
+    /// Requires an authenticated client, see [`GeckoTerminalAPI::with_api_key`].
+    ///
+    /// # Arguments
+    /// * `category_id` - The ID of the category to get the pools for.
+    /// * `page` - The page number of the results to return.
+    /// * `sort` - The sort order of the pools. This is optional and defaults to `H6Trending`.
+    pub async fn category_pools(
+        &self,
+        category_id: &str,
+        page: Option<i32>,
+        sort: Option<CategoryPoolSort>,
+    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, Error> {
+        check_api_key(&self.api_key)?;
+        let page = page.unwrap_or(1);
+        check_page(&page);
+        let sort = sort.unwrap_or(CategoryPoolSort::H6Trending);
+        let path = format!("/categories/{category_id}/pools");
+        let include_str = "base_token,quote_token,dex,network";
+        let params = json!({ "page": page, "sort": sort.as_str(), "include": include_str });
+        let resp = self.get(path, params).await?;
+        self.format_response::<Vec<Pool>>(resp).await
+    }
+
+    /// Stream pools in a category, fetching pages lazily up to `MAX_PAGE`.
+    /// Requires an authenticated client, see [`GeckoTerminalAPI::with_api_key`].
+    ///
+    /// # Arguments
+    /// * `category_id` - The ID of the category to get the pools for.
+    /// * `sort` - The sort order of the pools. This is optional and defaults to `H6Trending`.
+    pub fn category_pools_stream<'a>(
+        &'a self,
+        category_id: &'a str,
+        sort: Option<CategoryPoolSort>,
+    ) -> impl Stream<Item = Result<Pool, Error>> + 'a {
+        paginate(move |page| self.category_pools(category_id, Some(page), sort))
+    }
+
     /// Get current USD prices of multiple tokens on a network.
     ///
+    /// Duplicate addresses are removed, and more than `MAX_ADDRESSES` addresses are split into
+    /// several requests whose prices are merged.
+    ///
     /// # Arguments
     /// * `network` - The network ID of the network to get the token prices for.
     /// * `addresses` - The addresses of the tokens to get the prices for.
@@ -311,15 +714,24 @@ impl GeckoTerminalAPI {
         &self,
         network: &str,
         addresses: Vec<&str>,
-    ) -> Result<GeckoTerminalResponse<TokenPrice>, reqwest::Error> {
-        check_addresses(&addresses);
-        let path = format!(
-            "/simple/networks/{network}/token_price/{}",
-            addresses.join(",")
-        );
-        let params = json!({});
-        let resp = self.get(path, params).await?;
-        self.format_response::<TokenPrice>(resp).await
+    ) -> Result<GeckoTerminalResponse<TokenPrice>, Error> {
+        let addresses = dedupe_addresses(&addresses);
+        let responses = fetch_chunks(&addresses, |chunk| async move {
+            let path = format!("/simple/networks/{network}/token_price/{chunk}");
+            let params = json!({});
+            let resp = self.get(path, params).await?;
+            self.format_response::<TokenPrice>(resp).await
+        })
+        .await?;
+        let mut responses = responses.into_iter();
+        let mut resp = responses.next().unwrap_or_default();
+        for other in responses {
+            resp.data
+                .attributes
+                .token_prices
+                .extend(other.data.attributes.token_prices);
+        }
+        Ok(resp)
     }
 
     /// Get top pools for a token on a network.
@@ -332,7 +744,7 @@ impl GeckoTerminalAPI {
         network: &str,
         token_address: &str,
         page: Option<i32>,
-    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, reqwest::Error> {
+    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, Error> {
         let page = page.unwrap_or(1);
         check_page(&page);
         let path = format!("/networks/{network}/tokens/{token_address}/pools");
@@ -342,6 +754,34 @@ impl GeckoTerminalAPI {
         self.format_response::<Vec<Pool>>(resp).await
     }
 
+    /// Get top pools for a token by its ID.
+    ///
+    /// # Arguments
+    /// * `token` - The ID of the token to get the pools for.
+    /// * `page` - The page number of the results to return.
+    pub async fn token_pools(
+        &self,
+        token: &TokenId,
+        page: Option<i32>,
+    ) -> Result<GeckoTerminalResponse<Vec<Pool>>, Error> {
+        token.validate()?;
+        self.network_token_pools(&token.network, &token.address, page)
+            .await
+    }
+
+    /// Stream top pools for a token on a network, fetching pages lazily up to `MAX_PAGE`.
+    ///
+    /// # Arguments
+    /// * `network` - The network ID of the network to get the pools for.
+    /// * `token_address` - The address of the token to get the pools for.
+    pub fn network_token_pools_stream<'a>(
+        &'a self,
+        network: &'a str,
+        token_address: &'a str,
+    ) -> impl Stream<Item = Result<Pool, Error>> + 'a {
+        paginate(move |page| self.network_token_pools(network, token_address, Some(page)))
+    }
+
     /// Get specific token on a network.
     ///
     /// # Arguments
@@ -351,7 +791,7 @@ impl GeckoTerminalAPI {
         &self,
         network: &str,
         address: &str,
-    ) -> Result<GeckoTerminalResponse<Token>, reqwest::Error> {
+    ) -> Result<GeckoTerminalResponse<Token>, Error> {
         let path = format!("/networks/{network}/tokens/{address}");
         let include_str = "top_pools";
         let params = json!({ "include": include_str });
@@ -359,8 +799,20 @@ impl GeckoTerminalAPI {
         self.format_response::<Token>(resp).await
     }
 
+    /// Get a specific token by its ID.
+    ///
+    /// # Arguments
+    /// * `token` - The ID of the token to get, e.g. taken from a pool's base token relationship.
+    pub async fn token(&self, token: &TokenId) -> Result<GeckoTerminalResponse<Token>, Error> {
+        token.validate()?;
+        self.network_token(&token.network, &token.address).await
+    }
+
     /// Get multiple tokens on a network.
     ///
+    /// Duplicate addresses are removed, and more than `MAX_ADDRESSES` addresses are split into
+    /// several requests whose results are merged, keeping the tokens in input order.
+    ///
     /// # Arguments
     /// * `network` - The network ID of the network to get the tokens for.
     /// * `addresses` - The addresses of the tokens to get.
@@ -368,13 +820,21 @@ impl GeckoTerminalAPI {
         &self,
         network: &str,
         addresses: Vec<&str>,
-    ) -> Result<GeckoTerminalResponse<Vec<Token>>, reqwest::Error> {
-        check_addresses(&addresses);
-        let path = format!("/networks/{network}/tokens/multi/{}", addresses.join(","));
-        let include_str = "top_pools";
-        let params = json!({ "include": include_str });
-        let resp = self.get(path, params).await?;
-        self.format_response::<Vec<Token>>(resp).await
+    ) -> Result<GeckoTerminalResponse<Vec<Token>>, Error> {
+        let addresses = dedupe_addresses(&addresses);
+        let responses = fetch_chunks(&addresses, |chunk| async move {
+            let path = format!("/networks/{network}/tokens/multi/{chunk}");
+            let include_str = "top_pools";
+            let params = json!({ "include": include_str });
+            let resp = self.get(path, params).await?;
+            self.format_response::<Vec<Token>>(resp).await
+        })
+        .await?;
+        let mut resp = merge_responses(responses);
+        sort_by_input_order(&mut resp.data, &addresses, |token| {
+            &token.attributes.address
+        });
+        Ok(resp)
     }
 
     /// Get token address info on a network.
@@ -386,7 +846,7 @@ impl GeckoTerminalAPI {
         &self,
         network: &str,
         address: &str,
-    ) -> Result<GeckoTerminalResponse<TokenInfo>, reqwest::Error> {
+    ) -> Result<GeckoTerminalResponse<TokenInfo>, Error> {
         let path = format!("/networks/{network}/tokens/{address}/info");
         let params = json!({});
         let resp = self.get(path, params).await?;
@@ -396,7 +856,7 @@ impl GeckoTerminalAPI {
     /// Get most recently updated 100 tokens info from all networks.
     pub async fn token_info_recently_updated(
         &self,
-    ) -> Result<GeckoTerminalResponse<Vec<TokenInfo>>, reqwest::Error> {
+    ) -> Result<GeckoTerminalResponse<Vec<TokenInfo>>, Error> {
         let path = "/tokens/info_recently_updated".to_string();
         let include_str = "network";
         let params = json!({ "include": include_str });
@@ -404,23 +864,134 @@ impl GeckoTerminalAPI {
         self.format_response::<Vec<TokenInfo>>(resp).await
     }
 
-    /// Get trades of a pool on a network.
+    /// Get the top holders of a token on a network, with their share of the supply.
+    /// Requires an authenticated client, see [`GeckoTerminalAPI::with_api_key`].
+    ///
+    /// # Arguments
+    /// * `network` - The network ID of the network to get the holders for.
+    /// * `token_address` - The address of the token to get the holders for.
+    /// * `holders` - The number of holders to return. This is optional and defaults to the API maximum.
+    /// * `include_pnl_details` - Whether to include realized and unrealized PnL of each holder. This is optional and defaults to false.
+    pub async fn network_token_top_holders(
+        &self,
+        network: &str,
+        token_address: &str,
+        holders: Option<i32>,
+        include_pnl_details: Option<bool>,
+    ) -> Result<GeckoTerminalResponse<TopHolders>, Error> {
+        check_api_key(&self.api_key)?;
+        let include_pnl_details = include_pnl_details.unwrap_or(false);
+        let path = format!("/networks/{network}/tokens/{token_address}/top_holders");
+        let mut params = json!({ "include_pnl_details": include_pnl_details });
+        if let Some(holders) = holders {
+            params["holders"] = json!(holders);
+        }
+        let resp = self.get(path, params).await?;
+        self.format_response::<TopHolders>(resp).await
+    }
+
+    /// Get the top holders of a token by its ID.
+    /// Requires an authenticated client, see [`GeckoTerminalAPI::with_api_key`].
+    ///
+    /// # Arguments
+    /// * `token` - The ID of the token to get the holders for.
+    /// * `holders` - The number of holders to return. This is optional and defaults to the API maximum.
+    /// * `include_pnl_details` - Whether to include realized and unrealized PnL of each holder. This is optional and defaults to false.
+    pub async fn token_top_holders(
+        &self,
+        token: &TokenId,
+        holders: Option<i32>,
+        include_pnl_details: Option<bool>,
+    ) -> Result<GeckoTerminalResponse<TopHolders>, Error> {
+        token.validate()?;
+        self.network_token_top_holders(&token.network, &token.address, holders, include_pnl_details)
+            .await
+    }
+
+    /// Get the top traders of a token on a network, ranked by PnL or volume.
+    /// Requires an authenticated client, see [`GeckoTerminalAPI::with_api_key`].
+    ///
+    /// # Arguments
+    /// * `network` - The network ID of the network to get the traders for.
+    /// * `token_address` - The address of the token to get the traders for.
+    /// * `traders` - The number of traders to return. This is optional and defaults to the API maximum.
+    /// * `sort` - The sort order of the traders. This is optional and defaults to `RealizedPnlUsdDesc`.
+    pub async fn network_token_top_traders(
+        &self,
+        network: &str,
+        token_address: &str,
+        traders: Option<i32>,
+        sort: Option<TraderSort>,
+    ) -> Result<GeckoTerminalResponse<TopTraders>, Error> {
+        check_api_key(&self.api_key)?;
+        let sort = sort.unwrap_or(TraderSort::RealizedPnlUsdDesc);
+        let path = format!("/networks/{network}/traders/{token_address}");
+        let mut params = json!({ "sort": sort.as_str(), "include_address_label": true });
+        if let Some(traders) = traders {
+            params["traders"] = json!(traders);
+        }
+        let resp = self.get(path, params).await?;
+        self.format_response::<TopTraders>(resp).await
+    }
+
+    /// Get the top traders of a token by its ID, ranked by PnL or volume.
+    /// Requires an authenticated client, see [`GeckoTerminalAPI::with_api_key`].
+    ///
+    /// # Arguments
+    /// * `token` - The ID of the token to get the traders for.
+    /// * `traders` - The number of traders to return. This is optional and defaults to the API maximum.
+    /// * `sort` - The sort order of the traders. This is optional and defaults to `RealizedPnlUsdDesc`.
+    pub async fn token_top_traders(
+        &self,
+        token: &TokenId,
+        traders: Option<i32>,
+        sort: Option<TraderSort>,
+    ) -> Result<GeckoTerminalResponse<TopTraders>, Error> {
+        token.validate()?;
+        self.network_token_top_traders(&token.network, &token.address, traders, sort)
+            .await
+    }
+
+    /// Get trades of a pool on a network, most recent first.
     ///
     /// # Arguments
     /// * `network` - The network ID of the network to get the trades for.
     /// * `pool_address` - The address of the pool to get the trades for.
-    /// * `trade_volume_in_usd_greater_than` - The minimum trade volume in USD to filter by.
+    /// * `options` - Optional minimum trade volume in USD and token filter.
     pub async fn network_pool_trades(
         &self,
         network: &str,
         pool_address: &str,
-        trade_volume_in_usd_greater_than: f64,
-    ) -> Result<GeckoTerminalResponse<Vec<Trade>>, reqwest::Error> {
+        options: Option<TradeOptions<'_>>,
+    ) -> Result<GeckoTerminalResponse<Vec<Trade>>, Error> {
+        let options = options.unwrap_or_default();
         let path = format!("/networks/{network}/pools/{pool_address}/trades");
-        let params =
-            json!({ "trade_volume_in_usd_greater_than": trade_volume_in_usd_greater_than });
+        let mut params = json!({});
+        if let Some(volume) = options.trade_volume_in_usd_greater_than {
+            params["trade_volume_in_usd_greater_than"] = json!(volume);
+        }
+        if let Some(token) = options.token {
+            params["token"] = json!(token);
+        }
         let resp = self.get(path, params).await?;
-        self.format_response::<Vec<Trade>>(resp).await
+        let mut resp = self.format_response::<Vec<Trade>>(resp).await?;
+        check_trade_order(&mut resp.data);
+        Ok(resp)
+    }
+
+    /// Get trades of a pool by its ID, most recent first.
+    ///
+    /// # Arguments
+    /// * `pool` - The ID of the pool to get the trades for.
+    /// * `options` - Optional minimum trade volume in USD and token filter.
+    pub async fn pool_trades(
+        &self,
+        pool: &PoolId,
+        options: Option<TradeOptions<'_>>,
+    ) -> Result<GeckoTerminalResponse<Vec<Trade>>, Error> {
+        pool.validate()?;
+        self.network_pool_trades(&pool.network, &pool.address, options)
+            .await
     }
 
     /// Fetches the OHLCV (Open, High, Low, Close, Volume) data for a specific pool on a network.
@@ -434,6 +1005,7 @@ impl GeckoTerminalAPI {
     /// * `limit` - The limit on the number of OHLCV data points to fetch. This is optional and defaults to 100.
     /// * `currency` - The currency in which the OHLCV data should be fetched. This is optional and defaults to "usd".
     /// * `token` - The token for which the OHLCV data should be fetched. This is optional and defaults to "base".
+    /// * `include_empty_intervals` - Whether to fill intervals without trades with empty candles. This is optional and defaults to false.
     #[allow(clippy::too_many_arguments)]
     pub async fn network_pool_ohlcv(
         &self,
@@ -445,33 +1017,276 @@ impl GeckoTerminalAPI {
         limit: Option<i32>,
         currency: Option<&str>,
         token: Option<&str>,
-    ) -> Result<GeckoTerminalResponse<OHLCV>, reqwest::Error> {
-        let aggregate = aggregate.unwrap_or(1);
-        let before_timestamp = before_timestamp.unwrap_or(
-            SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_secs(),
-        );
-        let limit = limit.unwrap_or(100);
-        let currency = currency.unwrap_or("usd");
+        include_empty_intervals: Option<bool>,
+    ) -> Result<GeckoTerminalResponse<OHLCV>, Error> {
         let token = token.unwrap_or("base");
-
-        check_timeframe(timeframe);
-        check_aggregate(&aggregate, timeframe);
-        check_ohlcv_limit(&limit);
-        check_currency(currency);
         check_token(token);
 
         let path = format!("/networks/{network}/pools/{pool_address}/ohlcv/{timeframe}");
-        let params = json!({ "aggregate": aggregate, "before_timestamp": before_timestamp, "limit": limit, "currency": currency, "token": token });
+        let mut params = ohlcv_params(
+            timeframe,
+            aggregate,
+            before_timestamp,
+            limit,
+            currency,
+            include_empty_intervals,
+        );
+        params["token"] = json!(token);
         let resp = self.get(path, params).await?;
-        self.format_response::<OHLCV>(resp).await
+        self.format_response(resp).await
+    }
+
+    /// Fetches the OHLCV data of a pool by its ID.
+    ///
+    /// # Arguments
+    /// * `pool` - The ID of the pool to get the OHLCV data for.
+    /// * The other arguments are those of [`GeckoTerminalAPI::network_pool_ohlcv`].
+    #[allow(clippy::too_many_arguments)]
+    pub async fn pool_ohlcv(
+        &self,
+        pool: &PoolId,
+        timeframe: &str,
+        aggregate: Option<i32>,
+        before_timestamp: Option<u64>,
+        limit: Option<i32>,
+        currency: Option<&str>,
+        token: Option<&str>,
+        include_empty_intervals: Option<bool>,
+    ) -> Result<GeckoTerminalResponse<OHLCV>, Error> {
+        pool.validate()?;
+        self.network_pool_ohlcv(
+            &pool.network,
+            &pool.address,
+            timeframe,
+            aggregate,
+            before_timestamp,
+            limit,
+            currency,
+            token,
+            include_empty_intervals,
+        )
+        .await
+    }
+
+    /// Fetches the OHLCV data of a pool between two timestamps, walking back page by page
+    /// from `end` and joining the pages into one series.
+    ///
+    /// The walk stops at `start`, or earlier at the start of the pool's history.
+    ///
+    /// # Arguments
+    /// * `network` - The network ID of the network to get the OHLCV data for.
+    /// * `pool_address` - The address of the pool to get the OHLCV data for.
+    /// * `timeframe` - The timeframe for the OHLCV data. This can be "day", "hour", etc.
+    /// * `aggregate` - The aggregate parameter for the OHLCV data. This is optional and defaults to 1.
+    /// * `start` - The timestamp of the oldest candle to fetch.
+    /// * `end` - The timestamp of the newest candle to fetch.
+    /// * `currency` - The currency in which the OHLCV data should be fetched. This is optional and defaults to "usd".
+    /// * `token` - The token for which the OHLCV data should be fetched. This is optional and defaults to "base".
+    /// * `include_empty_intervals` - Whether to fill intervals without trades with empty candles. This is optional and defaults to false.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn network_pool_ohlcv_range(
+        &self,
+        network: &str,
+        pool_address: &str,
+        timeframe: &str,
+        aggregate: Option<i32>,
+        start: u64,
+        end: u64,
+        currency: Option<&str>,
+        token: Option<&str>,
+        include_empty_intervals: Option<bool>,
+    ) -> Result<GeckoTerminalResponse<OHLCV>, Error> {
+        backfill_ohlcv(start, end, |before| {
+            self.network_pool_ohlcv(
+                network,
+                pool_address,
+                timeframe,
+                aggregate,
+                Some(before),
+                Some(OHLCV_LIMIT),
+                currency,
+                token,
+                include_empty_intervals,
+            )
+        })
+        .await
+    }
+
+    /// Fetches the OHLCV data of a pool by its ID between two timestamps.
+    ///
+    /// # Arguments
+    /// * `pool` - The ID of the pool to get the OHLCV data for.
+    /// * The other arguments are those of [`GeckoTerminalAPI::network_pool_ohlcv_range`].
+    #[allow(clippy::too_many_arguments)]
+    pub async fn pool_ohlcv_range(
+        &self,
+        pool: &PoolId,
+        timeframe: &str,
+        aggregate: Option<i32>,
+        start: u64,
+        end: u64,
+        currency: Option<&str>,
+        token: Option<&str>,
+        include_empty_intervals: Option<bool>,
+    ) -> Result<GeckoTerminalResponse<OHLCV>, Error> {
+        pool.validate()?;
+        self.network_pool_ohlcv_range(
+            &pool.network,
+            &pool.address,
+            timeframe,
+            aggregate,
+            start,
+            end,
+            currency,
+            token,
+            include_empty_intervals,
+        )
+        .await
+    }
+
+    /// Fetches the OHLCV data of a token on a network, aggregated across its pools.
+    /// Requires an authenticated client, see [`GeckoTerminalAPI::with_api_key`].
+    ///
+    /// # Arguments
+    /// * `network` - The network ID of the network to get the OHLCV data for.
+    /// * `token_address` - The address of the token to get the OHLCV data for.
+    /// * `timeframe` - The timeframe for the OHLCV data. This can be "day", "hour", etc.
+    /// * `aggregate` - The aggregate parameter for the OHLCV data. This is optional and defaults to 1.
+    /// * `before_timestamp` - The timestamp before which the OHLCV data should be fetched. This is optional and defaults to the current timestamp.
+    /// * `limit` - The limit on the number of OHLCV data points to fetch. This is optional and defaults to 100.
+    /// * `currency` - The currency in which the OHLCV data should be fetched. This is optional and defaults to "usd".
+    /// * `include_empty_intervals` - Whether to fill intervals without trades with empty candles. This is optional and defaults to false.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn network_token_ohlcv(
+        &self,
+        network: &str,
+        token_address: &str,
+        timeframe: &str,
+        aggregate: Option<i32>,
+        before_timestamp: Option<u64>,
+        limit: Option<i32>,
+        currency: Option<&str>,
+        include_empty_intervals: Option<bool>,
+    ) -> Result<GeckoTerminalResponse<OHLCV>, Error> {
+        check_api_key(&self.api_key)?;
+        let path = format!("/networks/{network}/tokens/{token_address}/ohlcv/{timeframe}");
+        let params = ohlcv_params(
+            timeframe,
+            aggregate,
+            before_timestamp,
+            limit,
+            currency,
+            include_empty_intervals,
+        );
+        let resp = self.get(path, params).await?;
+        self.format_response(resp).await
+    }
+
+    /// Fetches the OHLCV data of a token by its ID, aggregated across its pools.
+    /// Requires an authenticated client, see [`GeckoTerminalAPI::with_api_key`].
+    ///
+    /// # Arguments
+    /// * `token` - The ID of the token to get the OHLCV data for.
+    /// * The other arguments are those of [`GeckoTerminalAPI::network_token_ohlcv`].
+    #[allow(clippy::too_many_arguments)]
+    pub async fn token_ohlcv(
+        &self,
+        token: &TokenId,
+        timeframe: &str,
+        aggregate: Option<i32>,
+        before_timestamp: Option<u64>,
+        limit: Option<i32>,
+        currency: Option<&str>,
+        include_empty_intervals: Option<bool>,
+    ) -> Result<GeckoTerminalResponse<OHLCV>, Error> {
+        token.validate()?;
+        self.network_token_ohlcv(
+            &token.network,
+            &token.address,
+            timeframe,
+            aggregate,
+            before_timestamp,
+            limit,
+            currency,
+            include_empty_intervals,
+        )
+        .await
+    }
+
+    /// Get trades of a token on a network across all of its pools, most recent first.
+    /// Requires an authenticated client, see [`GeckoTerminalAPI::with_api_key`].
+    ///
+    /// # Arguments
+    /// * `network` - The network ID of the network to get the trades for.
+    /// * `token_address` - The address of the token to get the trades for.
+    /// * `options` - Optional minimum trade volume in USD.
+    pub async fn network_token_trades(
+        &self,
+        network: &str,
+        token_address: &str,
+        options: Option<TokenTradeOptions>,
+    ) -> Result<GeckoTerminalResponse<Vec<Trade>>, Error> {
+        check_api_key(&self.api_key)?;
+        let options = options.unwrap_or_default();
+        let path = format!("/networks/{network}/tokens/{token_address}/trades");
+        let mut params = json!({});
+        if let Some(volume) = options.trade_volume_in_usd_greater_than {
+            params["trade_volume_in_usd_greater_than"] = json!(volume);
+        }
+        let resp = self.get(path, params).await?;
+        let mut resp = self.format_response::<Vec<Trade>>(resp).await?;
+        check_trade_order(&mut resp.data);
+        Ok(resp)
+    }
+
+    /// Get trades of a token by its ID across all of its pools, most recent first.
+    /// Requires an authenticated client, see [`GeckoTerminalAPI::with_api_key`].
+    ///
+    /// # Arguments
+    /// * `token` - The ID of the token to get the trades for.
+    /// * `options` - Optional minimum trade volume in USD.
+    pub async fn token_trades(
+        &self,
+        token: &TokenId,
+        options: Option<TokenTradeOptions>,
+    ) -> Result<GeckoTerminalResponse<Vec<Trade>>, Error> {
+        token.validate()?;
+        self.network_token_trades(&token.network, &token.address, options)
+            .await
     }
 }
 
+/// Build and validate the query parameters shared by the OHLCV endpoints.
+fn ohlcv_params(
+    timeframe: &str,
+    aggregate: Option<i32>,
+    before_timestamp: Option<u64>,
+    limit: Option<i32>,
+    currency: Option<&str>,
+    include_empty_intervals: Option<bool>,
+) -> Value {
+    let aggregate = aggregate.unwrap_or(1);
+    let before_timestamp = before_timestamp.unwrap_or(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs(),
+    );
+    let limit = limit.unwrap_or(100);
+    let currency = currency.unwrap_or("usd");
+    let include_empty_intervals = include_empty_intervals.unwrap_or(false);
+
+    check_timeframe(timeframe);
+    check_aggregate(&aggregate, timeframe);
+    check_ohlcv_limit(&limit);
+    check_currency(currency);
+
+    json!({ "aggregate": aggregate, "before_timestamp": before_timestamp, "limit": limit, "currency": currency, "include_empty_intervals": include_empty_intervals })
+}
+
 #[cfg(test)]
 mod tests {
+    use futures::{StreamExt, TryStreamExt};
     use more_asserts::assert_gt;
 
     use super::*;
@@ -601,6 +1416,31 @@ mod tests {
         assert_eq!(resp.data.type_field, "simple_token_price");
     }
 
+    #[tokio::test]
+    async fn test_network_pools_multi_address_chunked() {
+        let client = GeckoTerminalAPI::new();
+        let pools = client.network_pools("eth", None).await.unwrap();
+        let more = client.network_pools("eth", Some(2)).await.unwrap();
+        let mut addresses: Vec<&str> = pools
+            .data
+            .iter()
+            .chain(more.data.iter())
+            .map(|pool| pool.attributes.address.as_str())
+            .collect();
+        let expected = addresses.clone();
+        addresses.push(expected[0]);
+        let resp = client
+            .network_pools_multi_address("eth", addresses)
+            .await
+            .unwrap();
+        let returned: Vec<&str> = resp
+            .data
+            .iter()
+            .map(|pool| pool.attributes.address.as_str())
+            .collect();
+        assert_eq!(returned, expected);
+    }
+
     #[tokio::test]
     async fn test_network_token_pools() {
         let client = GeckoTerminalAPI::new();
@@ -626,6 +1466,19 @@ mod tests {
         assert_eq!(resp.data.type_field, "token");
     }
 
+    #[tokio::test]
+    async fn test_pool_trades_by_id() {
+        let client = GeckoTerminalAPI::new();
+        let pools = client.network_pools("eth", None).await.unwrap();
+        let pool = &pools.data[0];
+        let resp = client.pool_trades(&pool.id, None).await.unwrap();
+        assert_eq!(resp.data[0].type_field, "trade");
+
+        let base_token = &pool.relationships.base_token.data.id;
+        let resp = client.token(base_token).await.unwrap();
+        assert_eq!(resp.data.id, *base_token);
+    }
+
     #[tokio::test]
     async fn test_network_token_multi_address() {
         let client = GeckoTerminalAPI::new();
@@ -669,13 +1522,41 @@ mod tests {
     async fn test_network_pool_trades() {
         let client = GeckoTerminalAPI::new();
         let resp = client
-            .network_pool_trades("eth", "0x60594a405d53811d3bc4766596efd80fd545a270", 1000.0)
+            .network_pool_trades(
+                "eth",
+                "0x60594a405d53811d3bc4766596efd80fd545a270",
+                Some(TradeOptions {
+                    trade_volume_in_usd_greater_than: Some(1000.0),
+                    ..Default::default()
+                }),
+            )
             .await
             .unwrap();
         assert_gt!(resp.data.len(), 100);
         assert_eq!(resp.data[0].type_field, "trade");
     }
 
+    #[tokio::test]
+    async fn test_network_pool_trades_token_filter() {
+        let client = GeckoTerminalAPI::new();
+        let resp = client
+            .network_pool_trades(
+                "eth",
+                "0x60594a405d53811d3bc4766596efd80fd545a270",
+                Some(TradeOptions {
+                    token: Some("quote"),
+                    ..Default::default()
+                }),
+            )
+            .await
+            .unwrap();
+        assert_gt!(resp.data.len(), 10);
+        assert!(resp
+            .data
+            .windows(2)
+            .all(|w| w[0].attributes.block_timestamp >= w[1].attributes.block_timestamp));
+    }
+
     #[tokio::test]
     async fn test_network_pool_ohlcv() {
         let client = GeckoTerminalAPI::new();
@@ -689,11 +1570,168 @@ mod tests {
                 None,
                 None,
                 None,
+                None,
             )
             .await
             .unwrap();
         assert_eq!(resp.data.type_field, "ohlcv_request_response");
         assert_eq!(resp.data.attributes.ohlcv_list.len(), 100);
+        assert!(resp.meta.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_network_token_ohlcv_unauthenticated() {
+        let client = GeckoTerminalAPI::new();
+        assert!(!client.is_authenticated());
+        let resp = client
+            .network_token_ohlcv(
+                "eth",
+                "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                "day",
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+        assert!(matches!(resp, Err(Error::MissingApiKey)));
+    }
+
+    #[tokio::test]
+    async fn test_network_token_trades_unauthenticated() {
+        let client = GeckoTerminalAPI::new();
+        let resp = client
+            .network_token_trades(
+                "eth",
+                "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                Some(TokenTradeOptions {
+                    trade_volume_in_usd_greater_than: Some(1000.0),
+                }),
+            )
+            .await;
+        assert!(matches!(resp, Err(Error::MissingApiKey)));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_id_rejected() {
+        let client = GeckoTerminalAPI::new();
+        let pool: PoolId = serde_json::from_str("\"bsc\"").unwrap();
+        let resp = client.pool(&pool).await;
+        assert!(matches!(resp, Err(Error::InvalidId(_))));
+        let resp = client.pool_trades(&pool, None).await;
+        assert!(matches!(resp, Err(Error::InvalidId(_))));
+        let token = TokenId::new("", "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48");
+        let resp = client.token_trades(&token, None).await;
+        assert!(matches!(resp, Err(Error::InvalidId(_))));
+    }
+
+    #[tokio::test]
+    async fn test_megafilter_pools_unauthenticated() {
+        let client = GeckoTerminalAPI::new();
+        let filter = PoolFilter::new()
+            .networks(&["eth"])
+            .reserve_in_usd_min(1000.0);
+        let resp = client.megafilter_pools(&filter).await;
+        assert!(matches!(resp, Err(Error::MissingApiKey)));
+    }
+
+    #[tokio::test]
+    async fn test_categories_unauthenticated() {
+        let client = GeckoTerminalAPI::new();
+        let resp = client.categories(None, None).await;
+        assert!(matches!(resp, Err(Error::MissingApiKey)));
+        let resp = client
+            .category_pools("meme", None, Some(CategoryPoolSort::H24VolumeUsdDesc))
+            .await;
+        assert!(matches!(resp, Err(Error::MissingApiKey)));
+    }
+
+    #[tokio::test]
+    async fn test_network_token_top_holders_unauthenticated() {
+        let client = GeckoTerminalAPI::new();
+        let resp = client
+            .network_token_top_holders(
+                "eth",
+                "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                Some(10),
+                None,
+            )
+            .await;
+        assert!(matches!(resp, Err(Error::MissingApiKey)));
+        let resp = client
+            .network_token_top_traders(
+                "eth",
+                "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48",
+                Some(10),
+                None,
+            )
+            .await;
+        assert!(matches!(resp, Err(Error::MissingApiKey)));
+    }
+
+    #[tokio::test]
+    async fn test_get_json() {
+        let client = GeckoTerminalAPI::new();
+        let resp: GeckoTerminalResponse<Vec<Network>> = client
+            .get_json("/networks", json!({ "page": 1 }))
+            .await
+            .unwrap();
+        assert_gt!(resp.data.len(), 10);
+        assert_eq!(resp.data[0].type_field, "network");
+    }
+
+    #[tokio::test]
+    async fn test_get_value() {
+        let client = GeckoTerminalAPI::new();
+        let resp = client
+            .get_value("/networks/eth/dexes", json!({}))
+            .await
+            .unwrap();
+        assert_eq!(resp["data"][0]["type"], "dex");
+    }
+
+    #[tokio::test]
+    async fn test_network_pools_stream() {
+        let client = GeckoTerminalAPI::new();
+        let pools: Vec<Pool> = client
+            .network_pools_stream("eth")
+            .take(25)
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(pools.len(), 25);
+        assert_eq!(pools[24].type_field, "pool");
+    }
+
+    #[tokio::test]
+    async fn test_network_pool_ohlcv_range() {
+        let client = GeckoTerminalAPI::new();
+        let end = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let start = end - 1500 * 3600;
+        let resp = client
+            .network_pool_ohlcv_range(
+                "eth",
+                "0x60594a405d53811d3bc4766596efd80fd545a270",
+                "hour",
+                None,
+                start,
+                end,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+        let ohlcv_list = resp.data.attributes.ohlcv_list;
+        assert_gt!(ohlcv_list.len(), 1000);
+        assert!(ohlcv_list
+            .windows(2)
+            .all(|w| w[0].timestamp < w[1].timestamp));
+        assert!(ohlcv_list[0].timestamp >= start as i64);
     }
 
     #[tokio::test]
@@ -1,5 +1,6 @@
 pub const MAX_PAGE: i32 = 10;
 pub const MAX_ADDRESSES: usize = 30;
+pub const MAX_CONCURRENT_CHUNKS: usize = 3;
 pub const OHLCV_LIMIT: i32 = 1000;
 
 pub const VALID_TIMEFRAMES: [&str; 3] = ["day", "hour", "minute"];
@@ -0,0 +1,533 @@
+use serde_json::{json, Value};
+
+/// Query options for the pool trades endpoint.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct TradeOptions<'a> {
+    /// Only return trades with a volume in USD greater than this value.
+    pub trade_volume_in_usd_greater_than: Option<f64>,
+    /// Only return trades of this token, can be "base", "quote" or a token address.
+    pub token: Option<&'a str>,
+}
+
+/// Query options for the token trades endpoint, which aggregates the trades of all of the
+/// token's pools.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct TokenTradeOptions {
+    /// Only return trades with a volume in USD greater than this value.
+    pub trade_volume_in_usd_greater_than: Option<f64>,
+}
+
+/// Sort order of the megafilter pools endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolSort {
+    M5Trending,
+    H1Trending,
+    H6Trending,
+    H24Trending,
+    H24TxCountDesc,
+    H24VolumeUsdDesc,
+    M5PriceChangePercentageAsc,
+    H1PriceChangePercentageAsc,
+    H6PriceChangePercentageAsc,
+    H24PriceChangePercentageAsc,
+    M5PriceChangePercentageDesc,
+    H1PriceChangePercentageDesc,
+    H6PriceChangePercentageDesc,
+    H24PriceChangePercentageDesc,
+    FdvUsdAsc,
+    FdvUsdDesc,
+    ReserveInUsdAsc,
+    ReserveInUsdDesc,
+    PriceAsc,
+    PriceDesc,
+    PoolCreatedAtDesc,
+}
+
+impl PoolSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PoolSort::M5Trending => "m5_trending",
+            PoolSort::H1Trending => "h1_trending",
+            PoolSort::H6Trending => "h6_trending",
+            PoolSort::H24Trending => "h24_trending",
+            PoolSort::H24TxCountDesc => "h24_tx_count_desc",
+            PoolSort::H24VolumeUsdDesc => "h24_volume_usd_desc",
+            PoolSort::M5PriceChangePercentageAsc => "m5_price_change_percentage_asc",
+            PoolSort::H1PriceChangePercentageAsc => "h1_price_change_percentage_asc",
+            PoolSort::H6PriceChangePercentageAsc => "h6_price_change_percentage_asc",
+            PoolSort::H24PriceChangePercentageAsc => "h24_price_change_percentage_asc",
+            PoolSort::M5PriceChangePercentageDesc => "m5_price_change_percentage_desc",
+            PoolSort::H1PriceChangePercentageDesc => "h1_price_change_percentage_desc",
+            PoolSort::H6PriceChangePercentageDesc => "h6_price_change_percentage_desc",
+            PoolSort::H24PriceChangePercentageDesc => "h24_price_change_percentage_desc",
+            PoolSort::FdvUsdAsc => "fdv_usd_asc",
+            PoolSort::FdvUsdDesc => "fdv_usd_desc",
+            PoolSort::ReserveInUsdAsc => "reserve_in_usd_asc",
+            PoolSort::ReserveInUsdDesc => "reserve_in_usd_desc",
+            PoolSort::PriceAsc => "price_asc",
+            PoolSort::PriceDesc => "price_desc",
+            PoolSort::PoolCreatedAtDesc => "pool_created_at_desc",
+        }
+    }
+}
+
+/// Sort order of the categories endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CategorySort {
+    H1VolumePercentageDesc,
+    H6VolumePercentageDesc,
+    H12VolumePercentageDesc,
+    H24TxCountDesc,
+    H24VolumeUsdDesc,
+    FdvUsdDesc,
+    ReserveInUsdDesc,
+}
+
+impl CategorySort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CategorySort::H1VolumePercentageDesc => "h1_volume_percentage_desc",
+            CategorySort::H6VolumePercentageDesc => "h6_volume_percentage_desc",
+            CategorySort::H12VolumePercentageDesc => "h12_volume_percentage_desc",
+            CategorySort::H24TxCountDesc => "h24_tx_count_desc",
+            CategorySort::H24VolumeUsdDesc => "h24_volume_usd_desc",
+            CategorySort::FdvUsdDesc => "fdv_usd_desc",
+            CategorySort::ReserveInUsdDesc => "reserve_in_usd_desc",
+        }
+    }
+}
+
+/// Sort order of the category pools endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CategoryPoolSort {
+    M5Trending,
+    H1Trending,
+    H6Trending,
+    H24Trending,
+    H24TxCountDesc,
+    H24VolumeUsdDesc,
+    PoolCreatedAtDesc,
+    H24PriceChangePercentageAsc,
+    H24PriceChangePercentageDesc,
+}
+
+impl CategoryPoolSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CategoryPoolSort::M5Trending => "m5_trending",
+            CategoryPoolSort::H1Trending => "h1_trending",
+            CategoryPoolSort::H6Trending => "h6_trending",
+            CategoryPoolSort::H24Trending => "h24_trending",
+            CategoryPoolSort::H24TxCountDesc => "h24_tx_count_desc",
+            CategoryPoolSort::H24VolumeUsdDesc => "h24_volume_usd_desc",
+            CategoryPoolSort::PoolCreatedAtDesc => "pool_created_at_desc",
+            CategoryPoolSort::H24PriceChangePercentageAsc => "h24_price_change_percentage_asc",
+            CategoryPoolSort::H24PriceChangePercentageDesc => "h24_price_change_percentage_desc",
+        }
+    }
+}
+
+/// Sort order of the top traders endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraderSort {
+    RealizedPnlUsdDesc,
+    UnrealizedPnlUsdDesc,
+    TotalBuyUsdDesc,
+    TotalSellUsdDesc,
+}
+
+impl TraderSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TraderSort::RealizedPnlUsdDesc => "realized_pnl_usd_desc",
+            TraderSort::UnrealizedPnlUsdDesc => "unrealized_pnl_usd_desc",
+            TraderSort::TotalBuyUsdDesc => "total_buy_usd_desc",
+            TraderSort::TotalSellUsdDesc => "total_sell_usd_desc",
+        }
+    }
+}
+
+/// Time window that transaction count filters apply to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxWindow {
+    M5,
+    H1,
+    H6,
+    H24,
+}
+
+impl TxWindow {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TxWindow::M5 => "5m",
+            TxWindow::H1 => "1h",
+            TxWindow::H6 => "6h",
+            TxWindow::H24 => "24h",
+        }
+    }
+}
+
+/// Quality checks a pool must pass to be returned by the megafilter pools endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolCheck {
+    NoHoneypot,
+    GoodGtScore,
+    OnCoingecko,
+    HasSocial,
+}
+
+impl PoolCheck {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PoolCheck::NoHoneypot => "no_honeypot",
+            PoolCheck::GoodGtScore => "good_gt_score",
+            PoolCheck::OnCoingecko => "on_coingecko",
+            PoolCheck::HasSocial => "has_social",
+        }
+    }
+}
+
+/// Filters for the megafilter pools endpoint, serialized into query parameters.
+///
+/// # Examples
+///
+/// ```
+/// use geckoterminal_rs::options::{PoolCheck, PoolFilter, PoolSort, TxWindow};
+///
+/// let filter = PoolFilter::new()
+///     .networks(&["eth", "base"])
+///     .reserve_in_usd_min(10_000.0)
+///     .tx_count_min(100)
+///     .tx_count_duration(TxWindow::H24)
+///     .check(PoolCheck::NoHoneypot)
+///     .sort(PoolSort::H24VolumeUsdDesc);
+/// assert_eq!(filter.to_params()["networks"], "eth,base");
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct PoolFilter {
+    page: Option<i32>,
+    networks: Vec<String>,
+    dexes: Vec<String>,
+    sort: Option<PoolSort>,
+    fdv_usd_min: Option<f64>,
+    fdv_usd_max: Option<f64>,
+    reserve_in_usd_min: Option<f64>,
+    reserve_in_usd_max: Option<f64>,
+    h24_volume_usd_min: Option<f64>,
+    h24_volume_usd_max: Option<f64>,
+    pool_created_hour_min: Option<f64>,
+    pool_created_hour_max: Option<f64>,
+    buy_tax_percentage_min: Option<f64>,
+    buy_tax_percentage_max: Option<f64>,
+    sell_tax_percentage_min: Option<f64>,
+    sell_tax_percentage_max: Option<f64>,
+    tx_count_min: Option<u64>,
+    tx_count_max: Option<u64>,
+    tx_count_duration: Option<TxWindow>,
+    buys_min: Option<u64>,
+    buys_max: Option<u64>,
+    buys_duration: Option<TxWindow>,
+    sells_min: Option<u64>,
+    sells_max: Option<u64>,
+    sells_duration: Option<TxWindow>,
+    checks: Vec<PoolCheck>,
+    include_unknown_honeypot_tokens: Option<bool>,
+}
+
+impl PoolFilter {
+    #[must_use]
+    pub fn new() -> PoolFilter {
+        PoolFilter::default()
+    }
+
+    /// The page number of the results to return.
+    #[must_use]
+    pub fn page(mut self, page: i32) -> Self {
+        self.page = Some(page);
+        self
+    }
+
+    /// Only return pools on these network IDs.
+    #[must_use]
+    pub fn networks(mut self, networks: &[&str]) -> Self {
+        self.networks = networks.iter().map(|n| n.to_string()).collect();
+        self
+    }
+
+    /// Only return pools on these dex IDs, requires a single network.
+    #[must_use]
+    pub fn dexes(mut self, dexes: &[&str]) -> Self {
+        self.dexes = dexes.iter().map(|d| d.to_string()).collect();
+        self
+    }
+
+    #[must_use]
+    pub fn sort(mut self, sort: PoolSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    #[must_use]
+    pub fn fdv_usd_min(mut self, value: f64) -> Self {
+        self.fdv_usd_min = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn fdv_usd_max(mut self, value: f64) -> Self {
+        self.fdv_usd_max = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn reserve_in_usd_min(mut self, value: f64) -> Self {
+        self.reserve_in_usd_min = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn reserve_in_usd_max(mut self, value: f64) -> Self {
+        self.reserve_in_usd_max = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn h24_volume_usd_min(mut self, value: f64) -> Self {
+        self.h24_volume_usd_min = Some(value);
+        self
+    }
+
+    #[must_use]
+    pub fn h24_volume_usd_max(mut self, value: f64) -> Self {
+        self.h24_volume_usd_max = Some(value);
+        self
+    }
+
+    /// Minimum pool age in hours.
+    #[must_use]
+    pub fn pool_created_hour_min(mut self, hours: f64) -> Self {
+        self.pool_created_hour_min = Some(hours);
+        self
+    }
+
+    /// Maximum pool age in hours.
+    #[must_use]
+    pub fn pool_created_hour_max(mut self, hours: f64) -> Self {
+        self.pool_created_hour_max = Some(hours);
+        self
+    }
+
+    /// Minimum buy tax of the pool's tokens, in percent.
+    #[must_use]
+    pub fn buy_tax_percentage_min(mut self, percentage: f64) -> Self {
+        self.buy_tax_percentage_min = Some(percentage);
+        self
+    }
+
+    /// Maximum buy tax of the pool's tokens, in percent.
+    #[must_use]
+    pub fn buy_tax_percentage_max(mut self, percentage: f64) -> Self {
+        self.buy_tax_percentage_max = Some(percentage);
+        self
+    }
+
+    /// Minimum sell tax of the pool's tokens, in percent.
+    #[must_use]
+    pub fn sell_tax_percentage_min(mut self, percentage: f64) -> Self {
+        self.sell_tax_percentage_min = Some(percentage);
+        self
+    }
+
+    /// Maximum sell tax of the pool's tokens, in percent.
+    #[must_use]
+    pub fn sell_tax_percentage_max(mut self, percentage: f64) -> Self {
+        self.sell_tax_percentage_max = Some(percentage);
+        self
+    }
+
+    #[must_use]
+    pub fn tx_count_min(mut self, count: u64) -> Self {
+        self.tx_count_min = Some(count);
+        self
+    }
+
+    #[must_use]
+    pub fn tx_count_max(mut self, count: u64) -> Self {
+        self.tx_count_max = Some(count);
+        self
+    }
+
+    #[must_use]
+    pub fn tx_count_duration(mut self, window: TxWindow) -> Self {
+        self.tx_count_duration = Some(window);
+        self
+    }
+
+    #[must_use]
+    pub fn buys_min(mut self, count: u64) -> Self {
+        self.buys_min = Some(count);
+        self
+    }
+
+    #[must_use]
+    pub fn buys_max(mut self, count: u64) -> Self {
+        self.buys_max = Some(count);
+        self
+    }
+
+    #[must_use]
+    pub fn buys_duration(mut self, window: TxWindow) -> Self {
+        self.buys_duration = Some(window);
+        self
+    }
+
+    #[must_use]
+    pub fn sells_min(mut self, count: u64) -> Self {
+        self.sells_min = Some(count);
+        self
+    }
+
+    #[must_use]
+    pub fn sells_max(mut self, count: u64) -> Self {
+        self.sells_max = Some(count);
+        self
+    }
+
+    #[must_use]
+    pub fn sells_duration(mut self, window: TxWindow) -> Self {
+        self.sells_duration = Some(window);
+        self
+    }
+
+    /// Require pools to pass a check, can be called multiple times.
+    #[must_use]
+    pub fn check(mut self, check: PoolCheck) -> Self {
+        if !self.checks.contains(&check) {
+            self.checks.push(check);
+        }
+        self
+    }
+
+    #[must_use]
+    pub fn include_unknown_honeypot_tokens(mut self, include: bool) -> Self {
+        self.include_unknown_honeypot_tokens = Some(include);
+        self
+    }
+
+    /// The page number set on the filter, defaults to 1.
+    pub fn current_page(&self) -> i32 {
+        self.page.unwrap_or(1)
+    }
+
+    /// Serialize the filter into query parameters, leaving out unset filters.
+    pub fn to_params(&self) -> Value {
+        let mut params = json!({ "page": self.current_page() });
+        if !self.networks.is_empty() {
+            params["networks"] = json!(self.networks.join(","));
+        }
+        if !self.dexes.is_empty() {
+            params["dexes"] = json!(self.dexes.join(","));
+        }
+        if let Some(sort) = self.sort {
+            params["sort"] = json!(sort.as_str());
+        }
+        let ranges = [
+            ("fdv_usd_min", self.fdv_usd_min),
+            ("fdv_usd_max", self.fdv_usd_max),
+            ("reserve_in_usd_min", self.reserve_in_usd_min),
+            ("reserve_in_usd_max", self.reserve_in_usd_max),
+            ("h24_volume_usd_min", self.h24_volume_usd_min),
+            ("h24_volume_usd_max", self.h24_volume_usd_max),
+            ("pool_created_hour_min", self.pool_created_hour_min),
+            ("pool_created_hour_max", self.pool_created_hour_max),
+            ("buy_tax_percentage_min", self.buy_tax_percentage_min),
+            ("buy_tax_percentage_max", self.buy_tax_percentage_max),
+            ("sell_tax_percentage_min", self.sell_tax_percentage_min),
+            ("sell_tax_percentage_max", self.sell_tax_percentage_max),
+        ];
+        for (key, value) in ranges {
+            if let Some(value) = value {
+                params[key] = json!(value);
+            }
+        }
+        let counts = [
+            ("tx_count_min", self.tx_count_min),
+            ("tx_count_max", self.tx_count_max),
+            ("buys_min", self.buys_min),
+            ("buys_max", self.buys_max),
+            ("sells_min", self.sells_min),
+            ("sells_max", self.sells_max),
+        ];
+        for (key, value) in counts {
+            if let Some(value) = value {
+                params[key] = json!(value);
+            }
+        }
+        let windows = [
+            ("tx_count_duration", self.tx_count_duration),
+            ("buys_duration", self.buys_duration),
+            ("sells_duration", self.sells_duration),
+        ];
+        for (key, value) in windows {
+            if let Some(value) = value {
+                params[key] = json!(value.as_str());
+            }
+        }
+        if !self.checks.is_empty() {
+            let checks: Vec<&str> = self.checks.iter().map(PoolCheck::as_str).collect();
+            params["checks"] = json!(checks.join(","));
+        }
+        if let Some(include) = self.include_unknown_honeypot_tokens {
+            params["include_unknown_honeypot_tokens"] = json!(include);
+        }
+        params
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pool_filter_empty() {
+        let params = PoolFilter::new().to_params();
+        assert_eq!(params, json!({ "page": 1 }));
+    }
+
+    #[test]
+    fn test_pool_filter_params() {
+        let params = PoolFilter::new()
+            .page(2)
+            .networks(&["eth"])
+            .dexes(&["uniswap_v3", "sushiswap"])
+            .sort(PoolSort::PoolCreatedAtDesc)
+            .fdv_usd_max(1_000_000.0)
+            .pool_created_hour_max(24.0)
+            .buy_tax_percentage_max(5.0)
+            .sell_tax_percentage_max(10.0)
+            .buys_min(50)
+            .buys_duration(TxWindow::H1)
+            .check(PoolCheck::NoHoneypot)
+            .check(PoolCheck::HasSocial)
+            .check(PoolCheck::NoHoneypot)
+            .include_unknown_honeypot_tokens(false)
+            .to_params();
+        assert_eq!(
+            params,
+            json!({
+                "page": 2,
+                "networks": "eth",
+                "dexes": "uniswap_v3,sushiswap",
+                "sort": "pool_created_at_desc",
+                "fdv_usd_max": 1_000_000.0,
+                "pool_created_hour_max": 24.0,
+                "buy_tax_percentage_max": 5.0,
+                "sell_tax_percentage_max": 10.0,
+                "buys_min": 50,
+                "buys_duration": "1h",
+                "checks": "no_honeypot,has_social",
+                "include_unknown_honeypot_tokens": false,
+            })
+        );
+    }
+}
@@ -0,0 +1,294 @@
+use std::future::Future;
+use std::ops::RangeInclusive;
+
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+
+use crate::limits::{MAX_PAGE, OHLCV_LIMIT};
+use crate::types::ohlcv::{CandleSeries, OHLCV};
+use crate::types::response::GeckoTerminalResponse;
+use crate::Error;
+
+/// Turn a paged endpoint into a stream of its items.
+///
+/// Pages are fetched lazily starting from page 1, and the stream ends after the last page
+/// (no `links.next` or an empty page), at `MAX_PAGE`, or after yielding the first error.
+///
+/// # Arguments
+///
+/// * `fetch` - Fetches a single page given its page number.
+pub fn paginate<'a, T, M, F, Fut>(fetch: F) -> impl Stream<Item = Result<T, Error>> + 'a
+where
+    T: 'a,
+    M: 'a,
+    F: Fn(i32) -> Fut + 'a,
+    Fut: Future<Output = Result<GeckoTerminalResponse<Vec<T>, M>, Error>> + 'a,
+{
+    stream::try_unfold(Some(1), move |page| {
+        let fut = page.map(|page| (page, fetch(page)));
+        async move {
+            let Some((page, fut)) = fut else {
+                return Ok::<_, Error>(None);
+            };
+            let resp = fut.await?;
+            let next = if has_next_page(&resp, page) {
+                Some(page + 1)
+            } else {
+                None
+            };
+            Ok(Some((stream::iter(resp.data.into_iter().map(Ok)), next)))
+        }
+    })
+    .try_flatten()
+}
+
+/// Fetch a range of pages of a paged endpoint concurrently.
+///
+/// At most `concurrency` pages are in flight at once. Results are returned in page order,
+/// paired with their page number, and a failed page does not affect the other pages.
+///
+/// # Arguments
+///
+/// * `pages` - The range of page numbers to fetch.
+/// * `concurrency` - The maximum number of pages to fetch at the same time.
+/// * `fetch` - Fetches a single page given its page number.
+///
+/// # Examples
+///
+/// ```no_run
+/// use geckoterminal_rs::pagination::fetch_pages;
+/// use geckoterminal_rs::GeckoTerminalAPI;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let gt = GeckoTerminalAPI::new();
+///     let pages = fetch_pages(1..=10, 3, |page| gt.network_pools("eth", Some(page))).await;
+///     for (page, result) in pages {
+///         match result {
+///             Ok(resp) => println!("page {page}: {} pools", resp.data.len()),
+///             Err(err) => println!("page {page} failed: {err}"),
+///         }
+///     }
+/// }
+/// ```
+pub async fn fetch_pages<R, F, Fut>(
+    pages: RangeInclusive<i32>,
+    concurrency: usize,
+    fetch: F,
+) -> Vec<(i32, Result<R, Error>)>
+where
+    F: Fn(i32) -> Fut,
+    Fut: Future<Output = Result<R, Error>>,
+{
+    stream::iter(pages)
+        .map(|page| {
+            let fut = fetch(page);
+            async move { (page, fut.await) }
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+/// Walk an OHLCV endpoint backwards from `end` to `start` and join the pages into one series.
+///
+/// Each page requests `OHLCV_LIMIT` candles before the oldest candle of the previous page.
+/// The walk stops once `start` is reached, on an empty page, or on a page with fewer than
+/// `OHLCV_LIMIT` candles, which means the start of the pool's history was reached. Candles
+/// repeated across page boundaries are dropped and the series is sorted oldest first.
+///
+/// # Arguments
+///
+/// * `start` - The timestamp of the oldest candle to include.
+/// * `end` - The timestamp of the newest candle to include.
+/// * `fetch` - Fetches `OHLCV_LIMIT` candles before the given timestamp.
+pub(crate) async fn backfill_ohlcv<M, F, Fut>(
+    start: u64,
+    end: u64,
+    fetch: F,
+) -> Result<GeckoTerminalResponse<OHLCV, M>, Error>
+where
+    F: Fn(u64) -> Fut,
+    Fut: Future<Output = Result<GeckoTerminalResponse<OHLCV, M>, Error>>,
+{
+    let mut before = end;
+    let mut candles = Vec::new();
+    let mut first: Option<GeckoTerminalResponse<OHLCV, M>> = None;
+    loop {
+        let mut resp = fetch(before).await?;
+        let page = std::mem::take(&mut resp.data.attributes.ohlcv_list);
+        first.get_or_insert(resp);
+
+        let Some(oldest) = page.first().map(|candle| candle.timestamp as u64) else {
+            break;
+        };
+        candles.extend(
+            page.iter()
+                .filter(|candle| (start..=end).contains(&(candle.timestamp as u64))),
+        );
+        if oldest <= start || page.len() < OHLCV_LIMIT as usize || oldest >= before {
+            break;
+        }
+        before = oldest;
+    }
+
+    // Converting into a series sorts the candles and drops those repeated across pages.
+    let mut resp = first.expect("at least one page is fetched");
+    resp.data.attributes.ohlcv_list = CandleSeries::from(candles);
+    Ok(resp)
+}
+
+/// Whether a paged endpoint has another page after `page`.
+pub(crate) fn has_next_page<T, M>(resp: &GeckoTerminalResponse<Vec<T>, M>, page: i32) -> bool {
+    if resp.data.is_empty() || page >= MAX_PAGE {
+        return false;
+    }
+    match &resp.links {
+        Some(links) => links.next.is_some(),
+        None => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::types::ohlcv::Candle;
+    use crate::types::response::Links;
+
+    fn page_of(page: i32, len: usize, next: bool) -> GeckoTerminalResponse<Vec<i32>> {
+        GeckoTerminalResponse {
+            data: (0..len as i32).map(|i| page * 100 + i).collect(),
+            links: Some(Links {
+                next: next.then(|| format!("?page={}", page + 1)),
+                ..Default::default()
+            }),
+            meta: None,
+            included: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_at_last_page() {
+        let calls = Cell::new(0);
+        let items: Vec<i32> = paginate(|page| {
+            calls.set(calls.get() + 1);
+            async move { Ok(page_of(page, 2, page < 3)) }
+        })
+        .try_collect()
+        .await
+        .unwrap();
+        assert_eq!(items, vec![100, 101, 200, 201, 300, 301]);
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_stops_at_max_page() {
+        let items: Vec<i32> = paginate(|page| async move { Ok(page_of(page, 1, true)) })
+            .try_collect()
+            .await
+            .unwrap();
+        assert_eq!(items.len(), MAX_PAGE as usize);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pages_in_order() {
+        let in_flight = Cell::new(0);
+        let max_in_flight = Cell::new(0);
+        let pages = fetch_pages(1..=6, 2, |page| {
+            let (in_flight, max_in_flight) = (&in_flight, &max_in_flight);
+            async move {
+                in_flight.set(in_flight.get() + 1);
+                max_in_flight.set(max_in_flight.get().max(in_flight.get()));
+                // Later pages finish first to check that the order is preserved.
+                tokio::task::yield_now().await;
+                for _ in 0..(6 - page) {
+                    tokio::task::yield_now().await;
+                }
+                in_flight.set(in_flight.get() - 1);
+                Ok(page * 10)
+            }
+        })
+        .await;
+        let results: Vec<(i32, i32)> = pages.into_iter().map(|(p, r)| (p, r.unwrap())).collect();
+        assert_eq!(results, (1..=6).map(|p| (p, p * 10)).collect::<Vec<_>>());
+        assert_eq!(max_in_flight.get(), 2);
+    }
+
+    /// Serve hourly candles of a pool created at `created`, newest first like the API.
+    fn ohlcv_before(before: u64, created: u64) -> GeckoTerminalResponse<OHLCV> {
+        let mut resp = GeckoTerminalResponse {
+            data: OHLCV::default(),
+            links: None,
+            meta: None,
+            included: None,
+        };
+        // Include the candle at `before` to check de-duplication of page boundaries.
+        let newest = before - before % 3600;
+        let candles: Vec<Candle> = (0..OHLCV_LIMIT as u64)
+            .map(|i| newest.saturating_sub(i * 3600))
+            .take_while(|ts| *ts >= created)
+            .map(|ts| Candle {
+                timestamp: ts as i64,
+                ..Default::default()
+            })
+            .collect();
+        resp.data.attributes.ohlcv_list = CandleSeries::from(candles);
+        resp
+    }
+
+    #[tokio::test]
+    async fn test_backfill_ohlcv_range() {
+        let calls = Cell::new(0);
+        let (start, end) = (3600 * 10, 3600 * 2510);
+        let resp = backfill_ohlcv(start, end, |before| {
+            calls.set(calls.get() + 1);
+            async move { Ok(ohlcv_before(before, 0)) }
+        })
+        .await
+        .unwrap();
+        let timestamps: Vec<u64> = resp
+            .data
+            .attributes
+            .ohlcv_list
+            .iter()
+            .map(|c| c.timestamp as u64)
+            .collect();
+        assert_eq!(
+            timestamps,
+            (10..=2510).map(|h| h * 3600).collect::<Vec<_>>()
+        );
+        assert_eq!(calls.get(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_backfill_ohlcv_stops_at_pool_creation() {
+        let calls = Cell::new(0);
+        let resp = backfill_ohlcv(0, 3600 * 1500, |before| {
+            calls.set(calls.get() + 1);
+            async move { Ok(ohlcv_before(before, 3600 * 200)) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(resp.data.attributes.ohlcv_list.len(), 1301);
+        assert_eq!(resp.data.attributes.ohlcv_list[0].timestamp, 3600 * 200);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_paginate_is_lazy() {
+        let calls = Cell::new(0);
+        let items: Vec<i32> = paginate(|page| {
+            calls.set(calls.get() + 1);
+            async move { Ok(page_of(page, 20, true)) }
+        })
+        .take(25)
+        .try_collect()
+        .await
+        .unwrap();
+        assert_eq!(items.len(), 25);
+        assert_eq!(calls.get(), 2);
+    }
+}
@@ -0,0 +1,115 @@
+//! JSON Schemas of the endpoint responses, matching the structs in [`crate::types`].
+
+use schemars::schema::RootSchema;
+use schemars::schema_for;
+
+use crate::types::{
+    category::Category, dex::Dex, holder::TopHolders, network::Network, ohlcv::OHLCV, pool::Pool,
+    response::GeckoTerminalResponse, simple::TokenPrice, token::Token, token_info::TokenInfo,
+    trade::Trade, trader::TopTraders,
+};
+
+/// The response schema of every endpoint, keyed by the name of its client method.
+pub fn endpoint_schemas() -> Vec<(&'static str, RootSchema)> {
+    let pools = || schema_for!(GeckoTerminalResponse<Vec<Pool>>);
+    let ohlcv = || schema_for!(GeckoTerminalResponse<OHLCV>);
+    let trades = || schema_for!(GeckoTerminalResponse<Vec<Trade>>);
+    vec![
+        ("networks", schema_for!(GeckoTerminalResponse<Vec<Network>>)),
+        (
+            "network_dexes",
+            schema_for!(GeckoTerminalResponse<Vec<Dex>>),
+        ),
+        ("trending_pools", pools()),
+        ("network_trending_pools", pools()),
+        (
+            "network_pool_address",
+            schema_for!(GeckoTerminalResponse<Pool>),
+        ),
+        ("network_pools_multi_address", pools()),
+        ("network_pools", pools()),
+        ("network_dex_pools", pools()),
+        ("network_new_pools", pools()),
+        ("new_pools", pools()),
+        ("search_network_pool", pools()),
+        ("megafilter_pools", pools()),
+        (
+            "categories",
+            schema_for!(GeckoTerminalResponse<Vec<Category>>),
+        ),
+        ("category_pools", pools()),
+        (
+            "network_addresses_token_price",
+            schema_for!(GeckoTerminalResponse<TokenPrice>),
+        ),
+        ("network_token_pools", pools()),
+        ("network_token", schema_for!(GeckoTerminalResponse<Token>)),
+        (
+            "network_token_multi_address",
+            schema_for!(GeckoTerminalResponse<Vec<Token>>),
+        ),
+        (
+            "network_tokens_address_info",
+            schema_for!(GeckoTerminalResponse<TokenInfo>),
+        ),
+        (
+            "token_info_recently_updated",
+            schema_for!(GeckoTerminalResponse<Vec<TokenInfo>>),
+        ),
+        (
+            "network_token_top_holders",
+            schema_for!(GeckoTerminalResponse<TopHolders>),
+        ),
+        (
+            "network_token_top_traders",
+            schema_for!(GeckoTerminalResponse<TopTraders>),
+        ),
+        ("network_pool_trades", trades()),
+        ("network_pool_ohlcv", ohlcv()),
+        ("network_token_ohlcv", ohlcv()),
+        ("network_token_trades", trades()),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_endpoint_schemas() {
+        let schemas = endpoint_schemas();
+        let names: HashSet<_> = schemas.iter().map(|(name, _)| name).collect();
+        assert_eq!(names.len(), schemas.len());
+
+        let (_, token) = schemas
+            .iter()
+            .find(|(name, _)| *name == "network_token")
+            .unwrap();
+        // Same-named structs of different modules must not share a definition.
+        for definition in [
+            "TokenAttributes",
+            "PoolAttributes",
+            "TokenSummary",
+            "Included",
+        ] {
+            assert!(token.definitions.contains_key(definition), "{definition}");
+        }
+        let attributes = serde_json::to_value(&token.definitions["TokenAttributes"]).unwrap();
+        assert!(attributes["properties"]["total_supply"].is_object());
+        assert!(attributes["properties"].get("extra").is_none());
+
+        let (_, trades) = schemas
+            .iter()
+            .find(|(name, _)| *name == "network_pool_trades")
+            .unwrap();
+        let side = serde_json::to_value(&trades.definitions["TradeSide"]).unwrap();
+        assert_eq!(side["type"], "string");
+        assert!(side.get("enum").is_none());
+
+        let pool = serde_json::to_value(&token.definitions["PoolId"]).unwrap();
+        assert_eq!(pool["type"], "string");
+        assert!(pool.get("pattern").is_none());
+    }
+}
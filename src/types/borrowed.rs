@@ -0,0 +1,292 @@
+//! Borrowed variants of the largest list models, which deserialize without allocating a
+//! `String` per field.
+//!
+//! Text fields are `Cow<str>`s that borrow from the response body, so they must be
+//! deserialized with `serde_json::from_slice` or `from_str` from a buffer that outlives them,
+//! e.g. one returned by [`GeckoTerminalAPI::get_bytes`](crate::GeckoTerminalAPI::get_bytes).
+//! Only strings with escape sequences, which can not be borrowed, are copied. Numeric and
+//! time fields are kept as the raw strings sent by the API. Unlike the owned models, unknown
+//! fields are ignored rather than captured.
+
+use std::borrow::Cow;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+use crate::types::id::{ParseIdError, PoolId};
+use crate::types::pool::Transactions;
+use crate::types::trade::TradeSide;
+
+/// Deserialize an optional string, borrowing it like a `#[serde(borrow)] Cow<str>` field.
+/// Serde only borrows into bare `Cow<str>` fields, an `Option<Cow<str>>` would always copy.
+fn borrow_option<'de: 'a, 'a, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Cow<'a, str>>, D::Error> {
+    #[derive(Deserialize)]
+    struct Borrowed<'a>(#[serde(borrow)] Cow<'a, str>);
+
+    Ok(Option::<Borrowed>::deserialize(deserializer)?.map(|borrowed| borrowed.0))
+}
+
+/// A response whose `data` borrows from the body. Other sections, such as `included`, are
+/// skipped.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct ResponseRef<T> {
+    pub data: T,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PoolRef<'a> {
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    #[serde(rename = "type", borrow)]
+    pub type_field: Cow<'a, str>,
+    #[serde(borrow)]
+    pub attributes: PoolAttributesRef<'a>,
+    #[serde(borrow)]
+    pub relationships: PoolRelationshipsRef<'a>,
+}
+
+impl PoolRef<'_> {
+    pub fn pool_id(&self) -> Result<PoolId, ParseIdError> {
+        self.id.parse()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PoolAttributesRef<'a> {
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub base_token_price_usd: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub base_token_price_native_currency: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub quote_token_price_usd: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub quote_token_price_native_currency: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub base_token_price_quote_token: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub quote_token_price_base_token: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub address: Cow<'a, str>,
+    #[serde(borrow)]
+    pub name: Cow<'a, str>,
+    #[serde(borrow)]
+    pub pool_created_at: Cow<'a, str>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub token_price_usd: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub fdv_usd: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub market_cap_usd: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub price_change_percentage: PriceChangePercentageRef<'a>,
+    pub transactions: Transactions,
+    #[serde(borrow)]
+    pub volume_usd: VolumeUsdRef<'a>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub reserve_in_usd: Option<Cow<'a, str>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PriceChangePercentageRef<'a> {
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub m5: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub h1: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub h6: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub h24: Option<Cow<'a, str>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct VolumeUsdRef<'a> {
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub m5: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub h1: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub h6: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub h24: Option<Cow<'a, str>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct PoolRelationshipsRef<'a> {
+    #[serde(borrow)]
+    pub base_token: RelationshipRef<'a>,
+    #[serde(borrow)]
+    pub quote_token: RelationshipRef<'a>,
+    #[serde(borrow)]
+    pub network: Option<RelationshipRef<'a>>,
+    #[serde(borrow)]
+    pub dex: RelationshipRef<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct RelationshipRef<'a> {
+    #[serde(borrow)]
+    pub data: DataRef<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct DataRef<'a> {
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    #[serde(rename = "type", borrow)]
+    pub type_field: Cow<'a, str>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TradeRef<'a> {
+    #[serde(borrow)]
+    pub id: Cow<'a, str>,
+    #[serde(rename = "type", borrow)]
+    pub type_field: Cow<'a, str>,
+    #[serde(borrow)]
+    pub attributes: TradeAttributesRef<'a>,
+}
+
+impl TradeRef<'_> {
+    /// The USD value of the trade, parsed as `N` like
+    /// [`Trade::amount_of`](crate::types::trade::Trade::amount_of).
+    pub fn volume_in_usd<N: FromStr>(&self) -> Option<N> {
+        self.attributes.volume_in_usd.as_deref()?.parse().ok()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct TradeAttributesRef<'a> {
+    pub block_number: i64,
+    #[serde(borrow)]
+    pub tx_hash: Cow<'a, str>,
+    #[serde(borrow)]
+    pub tx_from_address: Cow<'a, str>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub from_token_amount: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub to_token_amount: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub price_from_in_currency_token: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub price_to_in_currency_token: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub price_from_in_usd: Option<Cow<'a, str>>,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub price_to_in_usd: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub block_timestamp: Cow<'a, str>,
+    pub kind: TradeSide,
+    #[serde(borrow, default, deserialize_with = "borrow_option")]
+    pub volume_in_usd: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub from_token_address: Cow<'a, str>,
+    #[serde(borrow)]
+    pub to_token_address: Cow<'a, str>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pool_ref_borrows() {
+        let body = r#"{"data": [{
+            "id": "eth_0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640",
+            "type": "pool",
+            "attributes": {
+                "base_token_price_usd": "1.0001",
+                "base_token_price_native_currency": null,
+                "quote_token_price_usd": "3481.66",
+                "quote_token_price_native_currency": "1.0",
+                "base_token_price_quote_token": "0.0003",
+                "quote_token_price_base_token": "3483.82",
+                "address": "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640",
+                "name": "USDC / WETH 0.05%",
+                "pool_created_at": "2021-12-29T12:35:14Z",
+                "fdv_usd": "36743302164",
+                "market_cap_usd": null,
+                "price_change_percentage": {"m5": "0.01", "h1": null, "h6": "0.43", "h24": "1.87"},
+                "transactions": {
+                    "m5": {"buys": 1, "sells": 2, "buyers": 1, "sellers": 2},
+                    "m15": {"buys": 1, "sells": 2, "buyers": 1, "sellers": 2},
+                    "m30": {"buys": 1, "sells": 2, "buyers": 1, "sellers": 2},
+                    "h1": {"buys": 1, "sells": 2, "buyers": 1, "sellers": 2},
+                    "h24": {"buys": 1, "sells": 2, "buyers": null, "sellers": null}
+                },
+                "volume_usd": {"m5": "1", "h1": "2", "h6": "3", "h24": "4"},
+                "reserve_in_usd": "172312987.4512",
+                "locked_liquidity_percentage": "0"
+            },
+            "relationships": {
+                "base_token": {"data": {"id": "eth_0xa0b8", "type": "token"}},
+                "quote_token": {"data": {"id": "eth_0xc02a", "type": "token"}},
+                "dex": {"data": {"id": "uniswap_v3", "type": "dex"}}
+            }
+        }], "included": [{"id": "uniswap_v3", "type": "dex", "attributes": {"name": "Uniswap V3"}}]}"#;
+        let resp: ResponseRef<Vec<PoolRef>> = serde_json::from_str(body).unwrap();
+        let pool = &resp.data[0];
+        assert!(matches!(pool.attributes.name, Cow::Borrowed(_)));
+        assert!(matches!(
+            pool.attributes.reserve_in_usd,
+            Some(Cow::Borrowed(_))
+        ));
+        assert_eq!(
+            pool.attributes.reserve_in_usd.as_deref(),
+            Some("172312987.4512")
+        );
+        assert_eq!(pool.attributes.price_change_percentage.h1, None);
+        assert_eq!(pool.relationships.dex.data.id, "uniswap_v3");
+        assert_eq!(pool.pool_id().unwrap().network, "eth");
+    }
+
+    #[test]
+    fn test_trade_ref_escaped_strings() {
+        let body = r#"{"data": [{
+            "id": "eth_19612255_0x0b8ac5_158_1712643143",
+            "type": "trade",
+            "attributes": {
+                "block_number": 19612255,
+                "tx_hash": "0x0b8ac5",
+                "tx_from_address": "0x42c037",
+                "from_token_amount": "1.12",
+                "to_token_amount": null,
+                "price_from_in_currency_token": "1.0",
+                "price_to_in_currency_token": "0.000287",
+                "price_from_in_usd": "3481.66",
+                "price_to_in_usd": "0.9999",
+                "block_timestamp": "2024-04-09T06:12:23Z",
+                "kind": "\u0062uy",
+                "volume_in_usd": "3899.86012345678901234",
+                "from_token_address": "0xc02a\/aa39",
+                "to_token_address": "0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"
+            }
+        }]}"#;
+        let resp: ResponseRef<Vec<TradeRef>> = serde_json::from_str(body).unwrap();
+        let trade = &resp.data[0];
+        let attributes = &trade.attributes;
+        assert_eq!(attributes.kind, TradeSide::Buy);
+        assert_eq!(attributes.from_token_address, "0xc02a/aa39");
+        assert!(matches!(attributes.from_token_address, Cow::Owned(_)));
+        assert!(matches!(attributes.to_token_address, Cow::Borrowed(_)));
+        assert!(matches!(attributes.volume_in_usd, Some(Cow::Borrowed(_))));
+        assert_eq!(attributes.to_token_amount, None);
+        assert_eq!(trade.volume_in_usd(), Some(3899.860123456789));
+        #[cfg(feature = "decimal")]
+        assert_eq!(
+            trade.volume_in_usd::<crate::types::BigDecimal>(),
+            Some("3899.86012345678901234".parse().unwrap())
+        );
+    }
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::decimal_accessors;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Category {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub attributes: Attributes,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "CategoryAttributes"))]
+#[non_exhaustive]
+pub struct Attributes {
+    pub name: String,
+    pub description: Option<String>,
+    pub volume_change_percentage: VolumeChangePercentage,
+    pub reserve_in_usd: Option<String>,
+    pub fdv_usd: Option<String>,
+    pub h24_volume_usd: Option<String>,
+    pub h24_tx_count: Option<i64>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+decimal_accessors!(Attributes {
+    reserve_in_usd,
+    fdv_usd,
+    h24_volume_usd,
+});
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct VolumeChangePercentage {
+    pub h1: Option<String>,
+    pub h6: Option<String>,
+    pub h12: Option<String>,
+    pub h24: Option<String>,
+}
+
+decimal_accessors!(VolumeChangePercentage { h1, h6, h12, h24 });
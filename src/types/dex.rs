@@ -1,13 +1,24 @@
+use std::collections::HashMap;
+
+use crate::types::id::DexId;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "DexAttributes"))]
+#[non_exhaustive]
 pub struct Attributes {
     pub name: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Dex {
-    pub id: String,
+    pub id: DexId,
     #[serde(rename = "type")]
     pub type_field: String,
     pub attributes: Attributes,
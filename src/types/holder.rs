@@ -0,0 +1,62 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::{datetime_accessors, decimal_accessors};
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct TopHolders {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub attributes: Attributes,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "TopHoldersAttributes"))]
+#[non_exhaustive]
+pub struct Attributes {
+    pub last_updated_at: Option<String>,
+    pub holders: Vec<Holder>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+datetime_accessors!(Attributes { last_updated_at });
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Holder {
+    pub rank: i64,
+    pub address: String,
+    pub label: Option<String>,
+    pub amount: Option<String>,
+    pub percentage: Option<String>,
+    pub value: Option<String>,
+    pub average_buy_price_usd: Option<String>,
+    pub total_buy_count: Option<i64>,
+    pub total_sell_count: Option<i64>,
+    pub unrealized_pnl_usd: Option<String>,
+    pub unrealized_pnl_percentage: Option<String>,
+    pub realized_pnl_usd: Option<String>,
+    pub realized_pnl_percentage: Option<String>,
+    pub explorer_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+decimal_accessors!(Holder {
+    amount,
+    percentage,
+    value,
+    average_buy_price_usd,
+    unrealized_pnl_usd,
+    unrealized_pnl_percentage,
+    realized_pnl_usd,
+    realized_pnl_percentage,
+});
@@ -0,0 +1,306 @@
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize};
+
+/// Error returned when a resource ID is not in the `{network}_{address}` form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseIdError(String);
+
+impl fmt::Display for ParseIdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid resource id {:?}, expected `{{network}}_{{address}}`",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseIdError {}
+
+/// Split an ID like `eth_0x6059...` into network and address.
+///
+/// The address starts after the first underscore, since addresses can contain underscores
+/// (`ton_EQ..._sDs`). The exception are multi-word EVM network IDs like `polygon_pos`: when the
+/// rest is a lowercase word followed by `_0x`, that word still belongs to the network.
+fn split_network_address(id: &str) -> Result<(NetworkId, String), ParseIdError> {
+    let (network, address) = id
+        .split_once('_')
+        .filter(|(network, address)| !network.is_empty() && !address.is_empty())
+        .ok_or_else(|| ParseIdError(id.to_string()))?;
+    let (network, address) = match address.split_once("_0x") {
+        Some((word, _))
+            if !address.starts_with("0x")
+                && !word.is_empty()
+                && word
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit()) =>
+        {
+            id.split_at(network.len() + 1 + word.len())
+        }
+        _ => (network, &id[network.len()..]),
+    };
+    Ok((NetworkId(network.to_string()), address[1..].to_string()))
+}
+
+/// ID of a network, e.g. `eth` or `polygon_pos`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct NetworkId(String);
+
+/// ID of a dex, e.g. `uniswap_v3`.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(transparent)]
+pub struct DexId(String);
+
+macro_rules! string_id {
+    ($name:ident) => {
+        impl $name {
+            pub fn new(id: &str) -> $name {
+                $name(id.to_string())
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = std::convert::Infallible;
+
+            fn from_str(s: &str) -> Result<$name, Self::Err> {
+                Ok($name::new(s))
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+    };
+}
+
+string_id!(NetworkId);
+string_id!(DexId);
+
+/// ID of a pool, `{network}_{address}`, e.g. `eth_0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640`.
+///
+/// An ID that is not in this form deserializes with an empty `network` and the whole ID as
+/// `address`, so one odd ID does not fail the whole response.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(into = "String")]
+pub struct PoolId {
+    pub network: NetworkId,
+    pub address: String,
+}
+
+/// ID of a token, `{network}_{address}`, e.g. `eth_0xa0b86991c6218b36c1d19d4a2e9eb0ce3606eb48`.
+///
+/// Deserializes leniently like [`PoolId`].
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize)]
+#[serde(into = "String")]
+pub struct TokenId {
+    pub network: NetworkId,
+    pub address: String,
+}
+
+macro_rules! network_address_id {
+    ($name:ident) => {
+        impl $name {
+            pub fn new(network: &str, address: &str) -> $name {
+                $name {
+                    network: NetworkId::new(network),
+                    address: address.to_string(),
+                }
+            }
+
+            /// Check that the ID has both a network and an address. IDs that were not in the
+            /// `{network}_{address}` form when deserialized have an empty network.
+            pub fn validate(&self) -> Result<(), ParseIdError> {
+                if self.network.is_empty() || self.address.is_empty() {
+                    return Err(ParseIdError(self.to_string()));
+                }
+                Ok(())
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                if self.network.is_empty() {
+                    f.write_str(&self.address)
+                } else {
+                    write!(f, "{}_{}", self.network, self.address)
+                }
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = ParseIdError;
+
+            fn from_str(s: &str) -> Result<$name, ParseIdError> {
+                let (network, address) = split_network_address(s)?;
+                Ok($name { network, address })
+            }
+        }
+
+        impl TryFrom<String> for $name {
+            type Error = ParseIdError;
+
+            fn try_from(s: String) -> Result<$name, ParseIdError> {
+                s.parse()
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<$name, D::Error> {
+                let id = String::deserialize(deserializer)?;
+                Ok(id.parse().unwrap_or_else(|err| {
+                    log::warn!("{err}");
+                    $name {
+                        network: NetworkId::default(),
+                        address: id,
+                    }
+                }))
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(id: $name) -> String {
+                id.to_string()
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                if self.network.is_empty() {
+                    return self.address == other;
+                }
+                other
+                    .strip_prefix(self.network.as_str())
+                    .and_then(|rest| rest.strip_prefix('_'))
+                    == Some(self.address.as_str())
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                *self == **other
+            }
+        }
+    };
+}
+
+network_address_id!(PoolId);
+network_address_id!(TokenId);
+
+#[cfg(feature = "schema")]
+macro_rules! network_address_id_schema {
+    ($name:ident) => {
+        impl schemars::JsonSchema for $name {
+            fn schema_name() -> String {
+                stringify!($name).to_string()
+            }
+
+            fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+                // No `{network}_{address}` pattern, IDs not in that form deserialize leniently.
+                String::json_schema(gen)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "schema")]
+network_address_id_schema!(PoolId);
+#[cfg(feature = "schema")]
+network_address_id_schema!(TokenId);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pool_id() {
+        let id: PoolId = "eth_0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640"
+            .parse()
+            .unwrap();
+        assert_eq!(id.network, "eth");
+        assert_eq!(id.address, "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640");
+        assert_eq!(
+            id.to_string(),
+            "eth_0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640"
+        );
+
+        let id: PoolId = "polygon_pos_0xa374094527e1673a86de625aa59517c5de346d32"
+            .parse()
+            .unwrap();
+        assert_eq!(id.network, "polygon_pos");
+        assert_eq!(id.address, "0xa374094527e1673a86de625aa59517c5de346d32");
+
+        let id: TokenId = "ton_EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs"
+            .parse()
+            .unwrap();
+        assert_eq!(id.network, "ton");
+        assert_eq!(
+            id.address,
+            "EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs"
+        );
+        assert_eq!(id, "ton_EQCxE6mUtQJKFnGfaROTKOt1lZbDiiX1kCixRv7Nw2Id_sDs");
+
+        let id: TokenId = "aptos_0x1::aptos_coin::AptosCoin".parse().unwrap();
+        assert_eq!(id.network, "aptos");
+        assert_eq!(id.address, "0x1::aptos_coin::AptosCoin");
+
+        let id: TokenId = "solana_So11111111111111111111111111111111111111112"
+            .parse()
+            .unwrap();
+        assert_eq!(id.address, "So11111111111111111111111111111111111111112");
+
+        assert!("eth".parse::<PoolId>().is_err());
+        assert!("_0x1".parse::<PoolId>().is_err());
+    }
+
+    #[test]
+    fn test_id_serde() {
+        let id: PoolId = serde_json::from_str("\"bsc_0x1\"").unwrap();
+        assert_eq!(id, PoolId::new("bsc", "0x1"));
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"bsc_0x1\"");
+        assert_eq!(id, "bsc_0x1");
+        assert_ne!(id, "bsc_0x2");
+
+        let id: PoolId = serde_json::from_str("\"bsc\"").unwrap();
+        assert_eq!(id.network, "");
+        assert_eq!(id.address, "bsc");
+        assert_eq!(id, "bsc");
+        assert_eq!(serde_json::to_string(&id).unwrap(), "\"bsc\"");
+        assert!(id.validate().is_err());
+        assert!(PoolId::new("bsc", "0x1").validate().is_ok());
+
+        let dex: DexId = serde_json::from_str("\"uniswap_v3\"").unwrap();
+        assert_eq!(dex, "uniswap_v3");
+    }
+}
@@ -1,9 +1,226 @@
+pub mod borrowed;
+pub mod category;
 pub mod dex;
+pub mod holder;
+pub mod id;
 pub mod network;
 pub mod ohlcv;
 pub mod pool;
+pub mod relationship;
 pub mod response;
 pub mod simple;
 pub mod token;
 pub mod token_info;
 pub mod trade;
+pub mod trader;
+
+/// With the `decimal` feature, numeric fields that the API sends as strings, such as prices,
+/// volumes or supplies, get a method of the same name that parses them into an exact
+/// `BigDecimal`. The fields themselves keep the raw strings in every build.
+#[cfg(feature = "decimal")]
+pub use bigdecimal::BigDecimal;
+
+/// A field holding a numeric string, which may be null.
+#[cfg(feature = "decimal")]
+pub(crate) trait RawDecimal {
+    fn raw(&self) -> Option<&str>;
+
+    /// The value as an exact decimal, `None` if it is null or not a number. Scientific
+    /// notation and values too large for fixed-size decimals are supported.
+    fn to_decimal(&self) -> Option<BigDecimal> {
+        self.raw()?.parse().ok()
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl RawDecimal for String {
+    fn raw(&self) -> Option<&str> {
+        Some(self)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl RawDecimal for Option<String> {
+    fn raw(&self) -> Option<&str> {
+        self.as_deref()
+    }
+}
+
+/// Add a method per numeric string field, named after it, that parses the field into an
+/// exact `BigDecimal` when the `decimal` feature is enabled.
+macro_rules! decimal_accessors {
+    ($ty:ty { $($field:ident),* $(,)? }) => {
+        #[cfg(feature = "decimal")]
+        impl $ty {
+            $(
+                #[doc = concat!("`", stringify!($field), "` as an exact decimal, `None` if it is null or not a number.")]
+                pub fn $field(&self) -> Option<crate::types::BigDecimal> {
+                    crate::types::RawDecimal::to_decimal(&self.$field)
+                }
+            )*
+        }
+    };
+}
+pub(crate) use decimal_accessors;
+
+/// With the `datetime` feature, timestamp fields, which the API sends as ISO 8601 strings or as
+/// seconds since the Unix epoch, get a method of the same name that parses them into a
+/// `chrono::DateTime<Utc>`. The fields themselves keep the raw values in every build.
+#[cfg(feature = "datetime")]
+pub(crate) trait RawTimestamp {
+    /// The value as a point in time, `None` if it is null, malformed or out of range.
+    fn to_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>>;
+}
+
+#[cfg(feature = "datetime")]
+impl RawTimestamp for String {
+    fn to_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.parse().ok()
+    }
+}
+
+#[cfg(feature = "datetime")]
+impl RawTimestamp for Option<String> {
+    fn to_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.as_ref()?.to_datetime()
+    }
+}
+
+#[cfg(feature = "datetime")]
+impl RawTimestamp for i64 {
+    fn to_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        unix_timestamp(*self)
+    }
+}
+
+/// The point in time `seconds` after the Unix epoch, `None` if it is out of range.
+#[cfg(feature = "datetime")]
+pub(crate) fn unix_timestamp(seconds: i64) -> Option<chrono::DateTime<chrono::Utc>> {
+    chrono::DateTime::from_timestamp(seconds, 0)
+}
+
+/// Add a method per timestamp field, named after it, that parses the field into a
+/// `chrono::DateTime<Utc>` when the `datetime` feature is enabled.
+macro_rules! datetime_accessors {
+    ($ty:ty { $($field:ident),* $(,)? }) => {
+        #[cfg(feature = "datetime")]
+        impl $ty {
+            $(
+                #[doc = concat!("`", stringify!($field), "` as a point in time, `None` if it is null, malformed or out of range.")]
+                pub fn $field(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+                    crate::types::RawTimestamp::to_datetime(&self.$field)
+                }
+            )*
+        }
+    };
+}
+pub(crate) use datetime_accessors;
+
+#[cfg(all(test, feature = "datetime"))]
+mod datetime_tests {
+    use chrono::{TimeZone, Utc};
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_datetime_fields() {
+        let mut pool = pool::Pool::default();
+        pool.attributes.pool_created_at = "2020-05-05T21:09:32Z".to_string();
+        assert_eq!(
+            pool.attributes.pool_created_at(),
+            Some(Utc.with_ymd_and_hms(2020, 5, 5, 21, 9, 32).unwrap())
+        );
+        assert!(pool.age().unwrap().num_days() > 365);
+
+        pool.attributes.pool_created_at = "yesterday".to_string();
+        assert_eq!(pool.attributes.pool_created_at(), None);
+        assert_eq!(pool.age(), None);
+
+        let holders: holder::Attributes =
+            serde_json::from_value(json!({"last_updated_at": null, "holders": []})).unwrap();
+        assert_eq!(holders.last_updated_at(), None);
+    }
+
+    #[test]
+    fn test_unix_timestamp() {
+        assert_eq!(
+            unix_timestamp(1709251200),
+            Some(Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap())
+        );
+        assert_eq!(unix_timestamp(i64::MAX), None);
+    }
+
+    #[test]
+    fn test_ohlcv_timestamps() {
+        let attributes: ohlcv::Attributes = serde_json::from_value(json!({
+            "ohlcv_list": [[1709251200, 1.0, 2.0, 0.5, 1.5, 100.0]]
+        }))
+        .unwrap();
+        assert_eq!(attributes.timestamps(), vec![1709251200]);
+        assert_eq!(
+            attributes.ohlcv_list[0].timestamp(),
+            Some(Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap())
+        );
+    }
+}
+
+#[cfg(all(test, feature = "decimal"))]
+mod tests {
+    use std::str::FromStr;
+
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_decimal_fields() {
+        let token: token::Attributes = serde_json::from_value(json!({
+            "address": "0x1",
+            "name": "Token",
+            "symbol": "TKN",
+            "image_url": "missing.png",
+            "coingecko_coin_id": "token",
+            "decimals": 18,
+            "total_supply": "123456789012345678901234567890123456789.0",
+            "price_usd": "1.5e-12",
+            "fdv_usd": "185.185",
+            "total_reserve_in_usd": "0.0",
+            "volume_usd": { "h24": "1000.123456789123456789" },
+            "market_cap_usd": null,
+        }))
+        .unwrap();
+        assert_eq!(
+            token.total_supply.as_deref(),
+            Some("123456789012345678901234567890123456789.0")
+        );
+        assert_eq!(
+            token.total_supply(),
+            Some(BigDecimal::from_str("123456789012345678901234567890123456789").unwrap())
+        );
+        assert_eq!(
+            token.price_usd(),
+            Some(BigDecimal::from_str("0.0000000000015").unwrap())
+        );
+        assert_eq!(
+            token.volume_usd.h24().unwrap().to_string(),
+            "1000.123456789123456789"
+        );
+        assert_eq!(token.market_cap_usd(), None);
+    }
+
+    #[test]
+    fn test_decimal_token_prices() {
+        let prices: simple::Attributes = serde_json::from_value(json!({
+            "token_prices": { "0x1": "0.99987", "0x2": null }
+        }))
+        .unwrap();
+        assert_eq!(
+            prices.token_price("0x1"),
+            Some(BigDecimal::from_str("0.99987").unwrap())
+        );
+        assert_eq!(prices.token_prices["0x2"], None);
+        assert_eq!(prices.token_price("0x2"), None);
+        assert_eq!(prices.token_price("0x3"), None);
+    }
+}
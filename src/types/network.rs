@@ -1,14 +1,25 @@
+use std::collections::HashMap;
+
+use crate::types::id::NetworkId;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "NetworkAttributes"))]
+#[non_exhaustive]
 pub struct Attributes {
     pub name: String,
     pub coingecko_asset_platform_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Network {
-    pub id: String,
+    pub id: NetworkId,
     #[serde(rename = "type")]
     pub type_field: String,
     pub attributes: Attributes,
@@ -1,6 +1,15 @@
-use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::ops::{Bound, Deref, RangeBounds};
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::types::datetime_accessors;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct OHLCV {
     pub id: String,
     #[serde(rename = "type")]
@@ -9,6 +18,236 @@ pub struct OHLCV {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "OHLCVAttributes"))]
+#[non_exhaustive]
 pub struct Attributes {
-    pub ohlcv_list: Vec<Vec<f64>>,
+    pub ohlcv_list: CandleSeries,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl Attributes {
+    /// The start time of each candle in `ohlcv_list`, in seconds since the Unix epoch.
+    pub fn timestamps(&self) -> Vec<i64> {
+        self.ohlcv_list
+            .iter()
+            .map(|candle| candle.timestamp)
+            .collect()
+    }
+}
+
+/// A single OHLCV candle, sent by the API as a
+/// `[timestamp, open, high, low, close, volume]` array.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct Candle {
+    /// The start time of the candle, in seconds since the Unix epoch.
+    pub timestamp: i64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+datetime_accessors!(Candle { timestamp });
+
+impl Serialize for Candle {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (
+            self.timestamp,
+            self.open,
+            self.high,
+            self.low,
+            self.close,
+            self.volume,
+        )
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Candle {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (timestamp, open, high, low, close, volume) =
+            <(f64, f64, f64, f64, f64, f64)>::deserialize(deserializer)?;
+        if !timestamp.is_finite() || timestamp.fract() != 0.0 {
+            return Err(D::Error::custom(format!(
+                "invalid candle timestamp {timestamp}"
+            )));
+        }
+        Ok(Candle {
+            timestamp: timestamp as i64,
+            open,
+            high,
+            low,
+            close,
+            volume,
+        })
+    }
+}
+
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for Candle {
+    fn schema_name() -> String {
+        "Candle".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        <(i64, f64, f64, f64, f64, f64)>::json_schema(gen)
+    }
+}
+
+/// Candles sorted by timestamp, oldest first, with at most one candle per timestamp.
+///
+/// The API sends candles newest first, they are sorted when the series is created.
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CandleSeries(Vec<Candle>);
+
+impl CandleSeries {
+    /// The candles whose timestamp falls in `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use geckoterminal_rs::types::ohlcv::{Candle, CandleSeries};
+    ///
+    /// let series = CandleSeries::from(vec![Candle::default(); 1]);
+    /// assert_eq!(series.range(..).len(), 1);
+    /// ```
+    pub fn range(&self, range: impl RangeBounds<i64>) -> &[Candle] {
+        self.range_by(range, |candle| candle.timestamp)
+    }
+
+    /// The candles whose start time, as ordered by `key`, falls in `range`. `key` must be
+    /// monotonic in the timestamp.
+    fn range_by<K: Ord>(
+        &self,
+        range: impl RangeBounds<K>,
+        key: impl Fn(&Candle) -> K,
+    ) -> &[Candle] {
+        let start = match range.start_bound() {
+            Bound::Included(t) => self.0.partition_point(|c| key(c) < *t),
+            Bound::Excluded(t) => self.0.partition_point(|c| key(c) <= *t),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(t) => self.0.partition_point(|c| key(c) <= *t),
+            Bound::Excluded(t) => self.0.partition_point(|c| key(c) < *t),
+            Bound::Unbounded => self.0.len(),
+        };
+        &self.0[start..end.max(start)]
+    }
+
+    pub fn into_vec(self) -> Vec<Candle> {
+        self.0
+    }
+}
+
+#[cfg(feature = "datetime")]
+impl CandleSeries {
+    /// The candles whose start time falls in `range`, e.g. `start..end` or `start..`.
+    pub fn time_range(&self, range: impl RangeBounds<chrono::DateTime<chrono::Utc>>) -> &[Candle] {
+        let range = (
+            range.start_bound().map(|t| Some(*t)),
+            range.end_bound().map(|t| Some(*t)),
+        );
+        self.range_by(range, Candle::timestamp)
+    }
+}
+
+impl From<Vec<Candle>> for CandleSeries {
+    fn from(mut candles: Vec<Candle>) -> Self {
+        candles.sort_by_key(|candle| candle.timestamp);
+        candles.dedup_by_key(|candle| candle.timestamp);
+        CandleSeries(candles)
+    }
+}
+
+impl Deref for CandleSeries {
+    type Target = [Candle];
+
+    fn deref(&self) -> &[Candle] {
+        &self.0
+    }
+}
+
+impl<'a> IntoIterator for &'a CandleSeries {
+    type Item = &'a Candle;
+    type IntoIter = std::slice::Iter<'a, Candle>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+impl<'de> Deserialize<'de> for CandleSeries {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<Candle>::deserialize(deserializer).map(CandleSeries::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_candle_series_from_api() {
+        let attributes: Attributes = serde_json::from_value(json!({
+            "ohlcv_list": [
+                [1709337600, 3.0, 3.5, 2.5, 3.2, 300.0],
+                [1709251200, 2.0, 2.5, 1.5, 2.2, 200.0],
+                [1709164800, 1.0, 1.5, 0.5, 1.2, 100.0],
+            ]
+        }))
+        .unwrap();
+        let series = &attributes.ohlcv_list;
+        assert_eq!(series.len(), 3);
+        assert_eq!(series[0].timestamp, 1709164800);
+        assert_eq!((series[0].high, series[0].low), (1.5, 0.5));
+        assert_eq!(series[2].volume, 300.0);
+
+        let value = serde_json::to_value(&attributes).unwrap();
+        assert_eq!(
+            value["ohlcv_list"][0],
+            json!([1709164800, 1.0, 1.5, 0.5, 1.2, 100.0])
+        );
+    }
+
+    #[test]
+    fn test_candle_series_range() {
+        let series = CandleSeries::from(
+            [40, 10, 30, 20, 30]
+                .iter()
+                .map(|ts| Candle {
+                    timestamp: *ts,
+                    ..Default::default()
+                })
+                .collect::<Vec<_>>(),
+        );
+        let timestamps =
+            |candles: &[Candle]| -> Vec<i64> { candles.iter().map(|c| c.timestamp).collect() };
+        assert_eq!(timestamps(&series), vec![10, 20, 30, 40]);
+        assert_eq!(timestamps(series.range(20..40)), vec![20, 30]);
+        assert_eq!(timestamps(series.range(20..=40)), vec![20, 30, 40]);
+        assert_eq!(timestamps(series.range(25..)), vec![30, 40]);
+        let (late, early) = (50, 10);
+        assert!(series.range(late..early).is_empty());
+
+        #[cfg(feature = "datetime")]
+        {
+            let t = |ts| crate::types::unix_timestamp(ts).unwrap();
+            assert_eq!(timestamps(series.time_range(t(20)..t(40))), vec![20, 30]);
+            let after_25 = t(25) + chrono::Duration::nanoseconds(1);
+            assert_eq!(timestamps(series.time_range(after_25..)), vec![30, 40]);
+        }
+    }
+
+    #[test]
+    fn test_candle_invalid_timestamp() {
+        let candle = serde_json::from_value::<Candle>(json!([1.5, 1.0, 1.0, 1.0, 1.0, 1.0]));
+        assert!(candle.is_err());
+    }
 }
@@ -1,85 +1,132 @@
+use std::collections::HashMap;
+
+use crate::types::dex::Dex;
+use crate::types::id::{DexId, NetworkId, PoolId, TokenId};
+use crate::types::network::Network;
+use crate::types::relationship::Relationship;
+use crate::types::response::GeckoTerminalResponse;
+use crate::types::token::TokenSummary;
+use crate::types::{datetime_accessors, decimal_accessors};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Pool {
-    pub id: String,
+    pub id: PoolId,
     #[serde(rename = "type")]
     pub type_field: String,
     pub attributes: Attributes,
     pub relationships: Relationships,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+impl Pool {
+    /// The pool's base token from the response's `included` resources.
+    pub fn base_token<'r, T, M>(
+        &self,
+        resp: &'r GeckoTerminalResponse<T, M>,
+    ) -> Option<&'r TokenSummary> {
+        resp.resolve(&self.relationships.base_token.data)?
+            .as_token()
+    }
+
+    /// The pool's quote token from the response's `included` resources.
+    pub fn quote_token<'r, T, M>(
+        &self,
+        resp: &'r GeckoTerminalResponse<T, M>,
+    ) -> Option<&'r TokenSummary> {
+        resp.resolve(&self.relationships.quote_token.data)?
+            .as_token()
+    }
+
+    /// The pool's dex from the response's `included` resources.
+    pub fn dex<'r, T, M>(&self, resp: &'r GeckoTerminalResponse<T, M>) -> Option<&'r Dex> {
+        resp.resolve(&self.relationships.dex.data)?.as_dex()
+    }
+
+    /// The pool's network from the response's `included` resources.
+    pub fn network<'r, T, M>(&self, resp: &'r GeckoTerminalResponse<T, M>) -> Option<&'r Network> {
+        resp.resolve(&self.relationships.network.as_ref()?.data)?
+            .as_network()
+    }
+}
+
+#[cfg(feature = "datetime")]
+impl Pool {
+    /// Time since the pool was created, `None` if the creation time can not be parsed.
+    pub fn age(&self) -> Option<chrono::Duration> {
+        Some(chrono::Utc::now() - self.attributes.pool_created_at()?)
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "PoolAttributes"))]
+#[non_exhaustive]
 pub struct Attributes {
-    pub base_token_price_usd: String,
-    pub base_token_price_native_currency: String,
-    pub quote_token_price_usd: String,
-    pub quote_token_price_native_currency: String,
-    pub base_token_price_quote_token: String,
-    pub quote_token_price_base_token: String,
+    pub base_token_price_usd: Option<String>,
+    pub base_token_price_native_currency: Option<String>,
+    pub quote_token_price_usd: Option<String>,
+    pub quote_token_price_native_currency: Option<String>,
+    pub base_token_price_quote_token: Option<String>,
+    pub quote_token_price_base_token: Option<String>,
     pub address: String,
     pub name: String,
     pub pool_created_at: String,
+    pub token_price_usd: Option<String>,
     pub fdv_usd: Option<String>,
-    pub market_cap_usd: Value,
+    pub market_cap_usd: Option<String>,
     pub price_change_percentage: PriceChangePercentage,
     pub transactions: Transactions,
     pub volume_usd: VolumeUsd,
-    pub reserve_in_usd: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct PriceChangePercentage {
-    pub m5: String,
-    pub h1: String,
-    pub h6: String,
-    pub h24: String,
+    pub reserve_in_usd: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Transactions {
-    pub m5: M5,
-    pub m15: M15,
-    pub m30: M30,
-    pub h1: H1,
-    pub h24: H24,
-}
+decimal_accessors!(Attributes {
+    base_token_price_usd,
+    base_token_price_native_currency,
+    quote_token_price_usd,
+    quote_token_price_native_currency,
+    base_token_price_quote_token,
+    quote_token_price_base_token,
+    token_price_usd,
+    fdv_usd,
+    market_cap_usd,
+    reserve_in_usd,
+});
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct M5 {
-    pub buys: i64,
-    pub sells: i64,
-    pub buyers: Option<i64>,
-    pub sellers: Option<i64>,
-}
+datetime_accessors!(Attributes { pool_created_at });
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct M15 {
-    pub buys: i64,
-    pub sells: i64,
-    pub buyers: Option<i64>,
-    pub sellers: Option<i64>,
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct PriceChangePercentage {
+    pub m5: Option<String>,
+    pub h1: Option<String>,
+    pub h6: Option<String>,
+    pub h24: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct M30 {
-    pub buys: i64,
-    pub sells: i64,
-    pub buyers: Option<i64>,
-    pub sellers: Option<i64>,
-}
+decimal_accessors!(PriceChangePercentage { m5, h1, h6, h24 });
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct H1 {
-    pub buys: i64,
-    pub sells: i64,
-    pub buyers: Option<i64>,
-    pub sellers: Option<i64>,
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Transactions {
+    pub m5: TransactionWindow,
+    pub m15: TransactionWindow,
+    pub m30: TransactionWindow,
+    pub h1: TransactionWindow,
+    pub h24: TransactionWindow,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct H24 {
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct TransactionWindow {
     pub buys: i64,
     pub sells: i64,
     pub buyers: Option<i64>,
@@ -87,44 +134,114 @@ pub struct H24 {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "PoolVolumeUsd"))]
+#[non_exhaustive]
 pub struct VolumeUsd {
-    pub m5: String,
-    pub h1: String,
-    pub h6: String,
-    pub h24: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Relationships {
-    pub base_token: BaseToken,
-    pub quote_token: QuoteToken,
-    pub network: Option<Network>,
-    pub dex: Dex,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Data {
-    pub id: String,
-    #[serde(rename = "type")]
-    pub type_field: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct BaseToken {
-    pub data: Data,
+    pub m5: Option<String>,
+    pub h1: Option<String>,
+    pub h6: Option<String>,
+    pub h24: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct QuoteToken {
-    pub data: Data,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Network {
-    pub data: Data,
-}
+decimal_accessors!(VolumeUsd { m5, h1, h6, h24 });
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Dex {
-    pub data: Data,
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "PoolRelationships"))]
+#[non_exhaustive]
+pub struct Relationships {
+    pub base_token: Relationship<TokenId>,
+    pub quote_token: Relationship<TokenId>,
+    pub network: Option<Relationship<NetworkId>>,
+    pub dex: Relationship<DexId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_token_included_pool_is_pool() {
+        let window = json!({"buys": 1, "sells": 2, "buyers": 1, "sellers": 2});
+        let change = json!({"m5": "0", "h1": "0.1", "h6": "0.2", "h24": "0.3"});
+        let pool: Pool = serde_json::from_value(json!({
+            "id": "eth_0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640",
+            "type": "pool",
+            "attributes": {
+                "base_token_price_usd": "1.0",
+                "base_token_price_native_currency": "0.0003",
+                "quote_token_price_usd": "3000.0",
+                "quote_token_price_native_currency": "1.0",
+                "base_token_price_quote_token": "0.0003",
+                "quote_token_price_base_token": "3000.0",
+                "address": "0x88e6a0c2ddd26feeb64f039a2c41296fcb3f5640",
+                "name": "USDC / WETH 0.05%",
+                "pool_created_at": "2021-12-29T12:35:14Z",
+                "token_price_usd": "1.0",
+                "fdv_usd": "1000000",
+                "market_cap_usd": null,
+                "price_change_percentage": change,
+                "transactions": {"m5": window, "m15": window, "m30": window, "h1": window, "h24": window},
+                "volume_usd": change,
+                "reserve_in_usd": "100000000",
+                "locked_liquidity_percentage": "12.5"
+            },
+            "relationships": {
+                "base_token": {"data": {"id": "eth_0xa0b8", "type": "token"}},
+                "quote_token": {"data": {"id": "eth_0xc02a", "type": "token"}},
+                "dex": {"data": {"id": "uniswap_v3", "type": "dex"}}
+            }
+        }))
+        .unwrap();
+
+        assert!(pool.attributes.token_price_usd.is_some());
+        assert!(pool.relationships.network.is_none());
+        assert_eq!(pool.relationships.dex.data.id, "uniswap_v3");
+        assert_eq!(pool.attributes.transactions.h24.sells, 2);
+        assert_eq!(pool.attributes.extra["locked_liquidity_percentage"], "12.5");
+    }
+
+    #[test]
+    fn test_resolve_relationships() {
+        fn relationship<I>(id: I, type_field: &str) -> Relationship<I> {
+            Relationship {
+                data: crate::types::relationship::Data {
+                    id,
+                    type_field: type_field.to_string(),
+                },
+            }
+        }
+        let pool = Pool {
+            relationships: Relationships {
+                base_token: relationship(TokenId::new("eth", "0xa"), "token"),
+                quote_token: relationship(TokenId::new("eth", "0xb"), "token"),
+                network: None,
+                dex: relationship(DexId::new("uniswap_v3"), "dex"),
+            },
+            ..Default::default()
+        };
+        let resp = GeckoTerminalResponse::<Vec<Pool>> {
+            data: vec![pool.clone()],
+            links: None,
+            meta: None,
+            included: serde_json::from_value(json!([
+                {
+                    "id": "eth_0xa",
+                    "type": "token",
+                    "attributes": {"address": "0xa", "name": "A", "symbol": "A", "image_url": null, "coingecko_coin_id": null}
+                },
+                {"id": "uniswap_v3", "type": "dex", "attributes": {"name": "Uniswap V3"}},
+                {"id": "eth_0xb", "type": "holder", "attributes": {}}
+            ]))
+            .unwrap(),
+        };
+
+        assert_eq!(pool.base_token(&resp).unwrap().id, "eth_0xa");
+        assert_eq!(pool.dex(&resp).unwrap().attributes.name, "Uniswap V3");
+        // Only a resource of another type shares the quote token id.
+        assert!(pool.quote_token(&resp).is_none());
+        assert!(pool.network(&resp).is_none());
+    }
 }
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+/// A reference to another resource, with its ID typed by the kind of resource, e.g.
+/// `Data<TokenId>` for a pool's base token.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Data<I> {
+    pub id: I,
+    #[serde(rename = "type")]
+    pub type_field: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Relationship<I> {
+    pub data: Data<I>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct RelationshipList<I> {
+    pub data: Vec<Data<I>>,
+}
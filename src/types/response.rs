@@ -1,57 +1,260 @@
-use serde::{Deserialize, Serialize};
+use std::fmt;
+
+use crate::types::dex::Dex;
+use crate::types::id::{DexId, NetworkId, PoolId, TokenId};
+use crate::types::network::Network;
+use crate::types::pool::Pool;
+use crate::types::relationship::Data;
+use crate::types::token::TokenSummary;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Links {
-    pub first: String,
-    pub last: String,
+    pub first: Option<String>,
+    pub last: Option<String>,
     pub prev: Option<String>,
     pub next: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Base {
     pub address: String,
     pub name: String,
     pub symbol: String,
-    pub coingecko_coin_id: String,
+    pub coingecko_coin_id: Option<String>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Quote {
     pub address: String,
     pub name: String,
     pub symbol: String,
-    pub coingecko_coin_id: String,
+    pub coingecko_coin_id: Option<String>,
 }
 
+/// The `meta` section of a response, e.g. the tokens an OHLCV series is priced in.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Meta {
     pub base: Base,
     pub quote: Quote,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Included {
-    pub id: String,
-    #[serde(rename = "type")]
-    pub type_field: String,
-    pub attributes: Attributes,
+/// A resource from the `included` list of a response, typed by its `type`.
+///
+/// Resource types this crate does not know about are kept as raw JSON in `Unknown`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+#[non_exhaustive]
+pub enum Included {
+    Token(TokenSummary),
+    Pool(Box<Pool>),
+    Dex(Dex),
+    Network(Network),
+    Unknown(Value),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Attributes {
-    pub address: Option<String>,
-    pub name: String,
-    pub symbol: Option<String>,
-    pub image_url: Option<String>,
-    pub coingecko_coin_id: Option<String>,
-    pub coingecko_asset_platform_id: Option<String>,
+/// The ID of an [`Included`] resource, borrowed from it.
+///
+/// Compares with typed IDs and `&str` IDs without formatting the pool and token IDs.
+#[derive(Debug, Clone, Copy, Eq)]
+pub enum IncludedId<'a> {
+    Token(&'a TokenId),
+    Pool(&'a PoolId),
+    Other(&'a str),
+}
+
+impl fmt::Display for IncludedId<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IncludedId::Token(id) => id.fmt(f),
+            IncludedId::Pool(id) => id.fmt(f),
+            IncludedId::Other(id) => f.write_str(id),
+        }
+    }
+}
+
+impl<'a> From<&'a PoolId> for IncludedId<'a> {
+    fn from(id: &'a PoolId) -> Self {
+        IncludedId::Pool(id)
+    }
+}
+
+impl<'a> From<&'a TokenId> for IncludedId<'a> {
+    fn from(id: &'a TokenId) -> Self {
+        IncludedId::Token(id)
+    }
+}
+
+impl<'a> From<&'a DexId> for IncludedId<'a> {
+    fn from(id: &'a DexId) -> Self {
+        IncludedId::Other(id.as_str())
+    }
+}
+
+impl<'a> From<&'a NetworkId> for IncludedId<'a> {
+    fn from(id: &'a NetworkId) -> Self {
+        IncludedId::Other(id.as_str())
+    }
+}
+
+impl<'a> From<&'a String> for IncludedId<'a> {
+    fn from(id: &'a String) -> Self {
+        IncludedId::Other(id)
+    }
+}
+
+impl PartialEq for IncludedId<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (IncludedId::Token(a), IncludedId::Token(b)) => a == b,
+            (IncludedId::Pool(a), IncludedId::Pool(b)) => a == b,
+            (IncludedId::Other(a), b) | (b, IncludedId::Other(a)) => *b == **a,
+            _ => false,
+        }
+    }
+}
+
+impl PartialEq<str> for IncludedId<'_> {
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            IncludedId::Token(id) => **id == *other,
+            IncludedId::Pool(id) => **id == *other,
+            IncludedId::Other(id) => *id == other,
+        }
+    }
+}
+
+impl PartialEq<&str> for IncludedId<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        *self == **other
+    }
+}
+
+impl Included {
+    pub fn id(&self) -> IncludedId<'_> {
+        match self {
+            Included::Token(token) => IncludedId::Token(&token.id),
+            Included::Pool(pool) => IncludedId::Pool(&pool.id),
+            Included::Dex(dex) => IncludedId::Other(dex.id.as_str()),
+            Included::Network(network) => IncludedId::Other(network.id.as_str()),
+            Included::Unknown(value) => IncludedId::Other(value["id"].as_str().unwrap_or_default()),
+        }
+    }
+
+    pub fn type_field(&self) -> &str {
+        match self {
+            Included::Token(token) => &token.type_field,
+            Included::Pool(pool) => &pool.type_field,
+            Included::Dex(dex) => &dex.type_field,
+            Included::Network(network) => &network.type_field,
+            Included::Unknown(value) => value["type"].as_str().unwrap_or_default(),
+        }
+    }
+
+    pub fn as_token(&self) -> Option<&TokenSummary> {
+        match self {
+            Included::Token(token) => Some(token),
+            _ => None,
+        }
+    }
+
+    pub fn as_pool(&self) -> Option<&Pool> {
+        match self {
+            Included::Pool(pool) => Some(pool),
+            _ => None,
+        }
+    }
+
+    pub fn as_dex(&self) -> Option<&Dex> {
+        match self {
+            Included::Dex(dex) => Some(dex),
+            _ => None,
+        }
+    }
+
+    pub fn as_network(&self) -> Option<&Network> {
+        match self {
+            Included::Network(network) => Some(network),
+            _ => None,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Included {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let included = match value["type"].as_str() {
+            Some("token") => serde_json::from_value(value).map(Included::Token),
+            Some("pool") => serde_json::from_value(value).map(Included::Pool),
+            Some("dex") => serde_json::from_value(value).map(Included::Dex),
+            Some("network") => serde_json::from_value(value).map(Included::Network),
+            _ => Ok(Included::Unknown(value)),
+        };
+        included.map_err(D::Error::custom)
+    }
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct GeckoTerminalResponse<T> {
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct GeckoTerminalResponse<T, M = Meta> {
     pub data: T,
     pub links: Option<Links>,
-    pub meta: Option<Meta>,
+    pub meta: Option<M>,
     pub included: Option<Vec<Included>>,
 }
+
+impl<T, M> GeckoTerminalResponse<T, M> {
+    /// Looks up the included resource referenced by a relationship, if the response has it.
+    pub fn resolve<'a, I>(&self, data: &'a Data<I>) -> Option<&Included>
+    where
+        &'a I: Into<IncludedId<'a>>,
+    {
+        let id = (&data.id).into();
+        self.included
+            .as_deref()?
+            .iter()
+            .find(|item| item.id() == id && item.type_field() == data.type_field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_included_by_type() {
+        let included: Vec<Included> = serde_json::from_value(json!([
+            {"id": "uniswap_v3", "type": "dex", "attributes": {"name": "Uniswap V3"}},
+            {
+                "id": "eth",
+                "type": "network",
+                "attributes": {"name": "Ethereum", "coingecko_asset_platform_id": "ethereum"}
+            },
+            {"id": "eth_0xa", "type": "holder", "attributes": {"rank": 1}}
+        ]))
+        .unwrap();
+
+        assert_eq!(included[0].as_dex().unwrap().attributes.name, "Uniswap V3");
+        assert_eq!(included[1].as_network().unwrap().id, "eth");
+        assert!(matches!(included[2], Included::Unknown(_)));
+        assert_eq!(included[2].id(), "eth_0xa");
+        assert_eq!(included[2].type_field(), "holder");
+
+        let roundtrip = serde_json::to_value(&included).unwrap();
+        assert_eq!(roundtrip[2]["attributes"]["rank"], 1);
+        assert_eq!(roundtrip[0]["type"], "dex");
+    }
+}
@@ -1,8 +1,11 @@
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct TokenPrice {
     pub id: String,
     #[serde(rename = "type")]
@@ -11,6 +14,20 @@ pub struct TokenPrice {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "TokenPriceAttributes"))]
+#[non_exhaustive]
 pub struct Attributes {
-    pub token_prices: HashMap<String, String>,
+    pub token_prices: HashMap<String, Option<String>>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+#[cfg(feature = "decimal")]
+impl Attributes {
+    /// The price of the token with the given address as an exact decimal, `None` if the
+    /// response has no price for it or it is not a number.
+    pub fn token_price(&self, address: &str) -> Option<crate::types::BigDecimal> {
+        crate::types::RawDecimal::to_decimal(self.token_prices.get(address)?)
+    }
 }
@@ -1,186 +1,103 @@
+use std::collections::HashMap;
+
+use crate::types::decimal_accessors;
+use crate::types::id::{PoolId, TokenId};
+use crate::types::pool::Pool;
+use crate::types::relationship::RelationshipList;
+use crate::types::response::GeckoTerminalResponse;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Token {
-    pub id: String,
+    pub id: TokenId,
     #[serde(rename = "type")]
     pub type_field: String,
     pub attributes: Attributes,
     pub relationships: Relationships,
 }
 
+impl Token {
+    /// The token's top pools found in the response's `included` resources, in relationship
+    /// order. Pools missing from `included` are skipped.
+    pub fn top_pools<'r, T, M>(&self, resp: &'r GeckoTerminalResponse<T, M>) -> Vec<&'r Pool> {
+        self.relationships
+            .top_pools
+            .data
+            .iter()
+            .filter_map(|data| resp.resolve(data)?.as_pool())
+            .collect()
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "TokenAttributes"))]
+#[non_exhaustive]
 pub struct Attributes {
     pub address: String,
     pub name: String,
     pub symbol: String,
-    pub image_url: String,
-    pub coingecko_coin_id: String,
-    pub decimals: i64,
-    pub total_supply: String,
-    pub price_usd: String,
-    pub fdv_usd: String,
-    pub total_reserve_in_usd: String,
+    pub image_url: Option<String>,
+    pub coingecko_coin_id: Option<String>,
+    pub decimals: Option<i64>,
+    pub total_supply: Option<String>,
+    pub price_usd: Option<String>,
+    pub fdv_usd: Option<String>,
+    pub total_reserve_in_usd: Option<String>,
     pub volume_usd: VolumeUsd,
     pub market_cap_usd: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
+decimal_accessors!(Attributes {
+    total_supply,
+    price_usd,
+    fdv_usd,
+    total_reserve_in_usd,
+    market_cap_usd,
+});
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "TokenVolumeUsd"))]
+#[non_exhaustive]
 pub struct VolumeUsd {
-    pub h24: String,
+    pub h24: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Relationships {
-    pub top_pools: TopPools,
-}
+decimal_accessors!(VolumeUsd { h24 });
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct TopPools {
-    pub data: Vec<Pool>,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Pool {
-    pub id: String,
-    #[serde(rename = "type")]
-    pub type_field: String,
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "TokenRelationships"))]
+#[non_exhaustive]
+pub struct Relationships {
+    pub top_pools: RelationshipList<PoolId>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Included {
-    pub id: String,
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct TokenSummary {
+    pub id: TokenId,
     #[serde(rename = "type")]
     pub type_field: String,
-    pub attributes: Attributes2,
-    pub relationships: Relationships2,
+    pub attributes: SummaryAttributes,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Attributes2 {
-    pub base_token_price_usd: String,
-    pub base_token_price_native_currency: String,
-    pub quote_token_price_usd: String,
-    pub quote_token_price_native_currency: String,
-    pub base_token_price_quote_token: String,
-    pub quote_token_price_base_token: String,
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct SummaryAttributes {
     pub address: String,
     pub name: String,
-    pub pool_created_at: String,
-    pub token_price_usd: String,
-    pub fdv_usd: String,
-    pub market_cap_usd: Value,
-    pub price_change_percentage: PriceChangePercentage,
-    pub transactions: Transactions,
-    pub volume_usd: VolumeUsd2,
-    pub reserve_in_usd: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct PriceChangePercentage {
-    pub m5: String,
-    pub h1: String,
-    pub h6: String,
-    pub h24: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Transactions {
-    pub m5: M5,
-    pub m15: M15,
-    pub m30: M30,
-    pub h1: H1,
-    pub h24: H24,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct M5 {
-    pub buys: i64,
-    pub sells: i64,
-    pub buyers: Option<i64>,
-    pub sellers: Option<i64>,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct M15 {
-    pub buys: i64,
-    pub sells: i64,
-    pub buyers: Option<i64>,
-    pub sellers: Option<i64>,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct M30 {
-    pub buys: i64,
-    pub sells: i64,
-    pub buyers: Option<i64>,
-    pub sellers: Option<i64>,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct H1 {
-    pub buys: i64,
-    pub sells: i64,
-    pub buyers: Option<i64>,
-    pub sellers: Option<i64>,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct H24 {
-    pub buys: i64,
-    pub sells: i64,
-    pub buyers: Option<i64>,
-    pub sellers: Option<i64>,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct VolumeUsd2 {
-    pub m5: String,
-    pub h1: String,
-    pub h6: String,
-    pub h24: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Relationships2 {
-    pub base_token: BaseToken,
-    pub quote_token: QuoteToken,
-    pub dex: Dex,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct BaseToken {
-    pub data: Data2,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Data2 {
-    pub id: String,
-    #[serde(rename = "type")]
-    pub type_field: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct QuoteToken {
-    pub data: Data3,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Data3 {
-    pub id: String,
-    #[serde(rename = "type")]
-    pub type_field: String,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Dex {
-    pub data: Data4,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Data4 {
-    pub id: String,
-    #[serde(rename = "type")]
-    pub type_field: String,
+    pub symbol: String,
+    pub image_url: Option<String>,
+    pub coingecko_coin_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
@@ -1,6 +1,13 @@
+use std::collections::HashMap;
+
+use crate::types::id::NetworkId;
+use crate::types::relationship::Relationship;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct TokenInfo {
     pub id: String,
     #[serde(rename = "type")]
@@ -10,33 +17,30 @@ pub struct TokenInfo {
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "TokenInfoAttributes"))]
+#[non_exhaustive]
 pub struct Attributes {
     pub address: String,
     pub name: String,
     pub symbol: String,
-    pub image_url: String,
+    pub image_url: Option<String>,
     pub coingecko_coin_id: Option<String>,
+    #[serde(default)]
     pub websites: Vec<String>,
     pub description: Option<String>,
     pub gt_score: Option<f64>,
     pub discord_url: Option<String>,
     pub telegram_handle: Option<String>,
     pub twitter_handle: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "TokenInfoRelationships"))]
+#[non_exhaustive]
 pub struct Relationships {
-    pub network: Network,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Network {
-    pub data: Data,
-}
-
-#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct Data {
-    pub id: String,
-    #[serde(rename = "type")]
-    pub type_field: String,
+    pub network: Relationship<NetworkId>,
 }
@@ -1,6 +1,23 @@
-use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+#[cfg(feature = "datetime")]
+use std::ops::RangeBounds;
+use std::ops::{AddAssign, Sub};
+use std::str::FromStr;
+
+#[cfg(feature = "datetime")]
+use chrono::{DateTime, Utc};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::chunking::same_address;
+use crate::types::pool::Pool;
+use crate::types::{datetime_accessors, decimal_accessors};
 
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
 pub struct Trade {
     pub id: String,
     #[serde(rename = "type")]
@@ -8,20 +25,367 @@ pub struct Trade {
     pub attributes: Attributes,
 }
 
+impl Trade {
+    /// Whether the trade bought or sold the token with the given address, or `None` if the
+    /// token is on neither side of the trade.
+    pub fn side_for(&self, token_address: &str) -> Option<TradeSide> {
+        if same_address(&self.attributes.to_token_address, token_address) {
+            Some(TradeSide::Buy)
+        } else if same_address(&self.attributes.from_token_address, token_address) {
+            Some(TradeSide::Sell)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the trade bought or sold the pool's base token.
+    pub fn side_for_pool(&self, pool: &Pool) -> Option<TradeSide> {
+        self.side_for(&pool.relationships.base_token.data.id.address)
+    }
+
+    /// The amount of the token with the given address that changed hands in the trade, parsed
+    /// as `N`, e.g. `f64`, or `BigDecimal` with the `decimal` feature for the exact amount.
+    pub fn amount_of<N: FromStr>(&self, token_address: &str) -> Option<N> {
+        let amount = match self.side_for(token_address)? {
+            TradeSide::Buy => &self.attributes.to_token_amount,
+            _ => &self.attributes.from_token_amount,
+        };
+        amount.as_deref()?.parse().ok()
+    }
+
+    /// The USD value of the trade, parsed as `N` like [`Trade::amount_of`].
+    pub fn volume_in_usd<N: FromStr>(&self) -> Option<N> {
+        self.attributes.volume_in_usd.as_deref()?.parse().ok()
+    }
+}
+
+#[cfg(feature = "datetime")]
+impl Trade {
+    /// Whether the trade happened in `range`. Trades whose time can not be parsed are in no
+    /// range.
+    pub fn is_in(&self, range: &impl RangeBounds<DateTime<Utc>>) -> bool {
+        self.attributes
+            .block_timestamp()
+            .is_some_and(|time| range.contains(&time))
+    }
+
+    /// Whether the trade happened in the time range from `start` (inclusive) to `end`
+    /// (exclusive).
+    pub fn is_between(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> bool {
+        self.is_in(&(start..end))
+    }
+
+    /// The trades that happened in `range`, in their original order.
+    ///
+    /// # Arguments
+    ///
+    /// * `trades` - The trades to filter.
+    /// * `range` - The time range, e.g. `start..end` or `start..`.
+    pub fn in_range<'a>(
+        trades: impl IntoIterator<Item = &'a Trade>,
+        range: impl RangeBounds<DateTime<Utc>>,
+    ) -> Vec<&'a Trade> {
+        trades
+            .into_iter()
+            .filter(|trade| trade.is_in(&range))
+            .collect()
+    }
+}
+
+/// The `kind` of a trade. Kinds other than `buy` and `sell` are kept as `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TradeSide {
+    Buy,
+    Sell,
+    Other(String),
+}
+
+impl TradeSide {
+    pub fn as_str(&self) -> &str {
+        match self {
+            TradeSide::Buy => "buy",
+            TradeSide::Sell => "sell",
+            TradeSide::Other(kind) => kind,
+        }
+    }
+}
+
+impl Default for TradeSide {
+    fn default() -> TradeSide {
+        TradeSide::Other(String::new())
+    }
+}
+
+impl fmt::Display for TradeSide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for TradeSide {
+    fn from(kind: &str) -> TradeSide {
+        match kind {
+            "buy" => TradeSide::Buy,
+            "sell" => TradeSide::Sell,
+            kind => TradeSide::Other(kind.to_string()),
+        }
+    }
+}
+
+impl Serialize for TradeSide {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for TradeSide {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<TradeSide, D::Error> {
+        struct KindVisitor;
+
+        impl Visitor<'_> for KindVisitor {
+            type Value = TradeSide;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a trade kind")
+            }
+
+            fn visit_str<E: de::Error>(self, kind: &str) -> Result<TradeSide, E> {
+                Ok(TradeSide::from(kind))
+            }
+
+            fn visit_string<E: de::Error>(self, kind: String) -> Result<TradeSide, E> {
+                Ok(match kind.as_str() {
+                    "buy" | "sell" => TradeSide::from(kind.as_str()),
+                    _ => TradeSide::Other(kind),
+                })
+            }
+        }
+
+        deserializer.deserialize_str(KindVisitor)
+    }
+}
+
+/// Any string, like the serde impls: `buy` and `sell` are only examples.
+#[cfg(feature = "schema")]
+impl schemars::JsonSchema for TradeSide {
+    fn schema_name() -> String {
+        "TradeSide".to_string()
+    }
+
+    fn json_schema(gen: &mut schemars::gen::SchemaGenerator) -> schemars::schema::Schema {
+        let mut schema = String::json_schema(gen).into_object();
+        schema.metadata().examples = vec!["buy".into(), "sell".into()];
+        schema.into()
+    }
+}
+
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "TradeAttributes"))]
+#[non_exhaustive]
 pub struct Attributes {
     pub block_number: i64,
     pub tx_hash: String,
     pub tx_from_address: String,
-    pub from_token_amount: String,
-    pub to_token_amount: String,
-    pub price_from_in_currency_token: String,
-    pub price_to_in_currency_token: String,
-    pub price_from_in_usd: String,
-    pub price_to_in_usd: String,
+    pub from_token_amount: Option<String>,
+    pub to_token_amount: Option<String>,
+    pub price_from_in_currency_token: Option<String>,
+    pub price_to_in_currency_token: Option<String>,
+    pub price_from_in_usd: Option<String>,
+    pub price_to_in_usd: Option<String>,
     pub block_timestamp: String,
-    pub kind: String,
-    pub volume_in_usd: String,
+    pub kind: TradeSide,
+    pub volume_in_usd: Option<String>,
     pub from_token_address: String,
     pub to_token_address: String,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+decimal_accessors!(Attributes {
+    from_token_amount,
+    to_token_amount,
+    price_from_in_currency_token,
+    price_to_in_currency_token,
+    price_from_in_usd,
+    price_to_in_usd,
+    volume_in_usd,
+});
+
+datetime_accessors!(Attributes { block_timestamp });
+
+/// Buy and sell counts and USD volumes of a set of trades, relative to one token.
+///
+/// Volumes are summed as `N`, `f64` by default. With the `decimal` feature,
+/// `TradeVolume<BigDecimal>` sums them exactly.
+#[derive(Default, Debug, Clone, Copy, PartialEq)]
+pub struct TradeVolume<N = f64> {
+    pub buys: usize,
+    pub sells: usize,
+    pub buy_volume_usd: N,
+    pub sell_volume_usd: N,
+}
+
+impl<N: FromStr + Default + AddAssign> TradeVolume<N> {
+    /// Split trades into buys and sells of the token with the given address. Trades that do
+    /// not involve the token, or whose volume can not be parsed, are ignored.
+    pub fn from_trades<'a>(
+        trades: impl IntoIterator<Item = &'a Trade>,
+        token_address: &str,
+    ) -> TradeVolume<N> {
+        let mut volume = TradeVolume::default();
+        for trade in trades {
+            let Some(volume_usd) = trade.volume_in_usd() else {
+                continue;
+            };
+            match trade.side_for(token_address) {
+                Some(TradeSide::Buy) => {
+                    volume.buys += 1;
+                    volume.buy_volume_usd += volume_usd;
+                }
+                Some(_) => {
+                    volume.sells += 1;
+                    volume.sell_volume_usd += volume_usd;
+                }
+                None => {}
+            }
+        }
+        volume
+    }
+}
+
+impl<N> TradeVolume<N>
+where
+    for<'a> &'a N: Sub<Output = N>,
+{
+    /// Buy volume minus sell volume, in USD.
+    pub fn net_volume_usd(&self) -> N {
+        &self.buy_volume_usd - &self.sell_volume_usd
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn trade(kind: &str, from: &str, to: &str, volume: &str) -> Trade {
+        serde_json::from_value(json!({
+            "id": "eth_1_0x1_0",
+            "type": "trade",
+            "attributes": {
+                "block_number": 1,
+                "tx_hash": "0x1",
+                "tx_from_address": "0x2",
+                "from_token_amount": "2.5",
+                "to_token_amount": "7500",
+                "price_from_in_currency_token": "1.0",
+                "price_to_in_currency_token": "0.0003",
+                "price_from_in_usd": "3000",
+                "price_to_in_usd": "1.0",
+                "block_timestamp": "2024-03-01T00:00:00Z",
+                "kind": kind,
+                "volume_in_usd": volume,
+                "from_token_address": from,
+                "to_token_address": to
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_trade_side() {
+        let buy = trade("buy", "0xWETH", "0xUSDC", "7500");
+        assert_eq!(buy.attributes.kind, TradeSide::Buy);
+        assert_eq!(buy.side_for("0xusdc"), Some(TradeSide::Buy));
+        assert_eq!(buy.side_for("0xweth"), Some(TradeSide::Sell));
+        assert_eq!(buy.side_for("0xdai"), None);
+        assert_eq!(buy.amount_of("0xUSDC"), Some(7500.0));
+        assert_eq!(buy.amount_of("0xWETH"), Some(2.5));
+        assert_eq!(buy.amount_of::<f64>("0xDAI"), None);
+
+        let sol = "So11111111111111111111111111111111111111112";
+        let solana = trade(
+            "sell",
+            sol,
+            "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v",
+            "1",
+        );
+        assert_eq!(solana.side_for(sol), Some(TradeSide::Sell));
+        assert_eq!(solana.side_for(&sol.to_lowercase()), None);
+
+        let other = trade("swap", "0xWETH", "0xUSDC", "1");
+        assert_eq!(other.attributes.kind, TradeSide::Other("swap".to_string()));
+        let value = serde_json::to_value(&other).unwrap();
+        assert_eq!(value["attributes"]["kind"], "swap");
+    }
+
+    #[test]
+    fn test_null_amounts() {
+        let mut value = serde_json::to_value(trade("buy", "0xWETH", "0xUSDC", "1")).unwrap();
+        value["attributes"]["to_token_amount"] = json!(null);
+        value["attributes"]["volume_in_usd"] = json!(null);
+        let trade: Trade = serde_json::from_value(value).unwrap();
+        assert_eq!(trade.amount_of::<f64>("0xUSDC"), None);
+        assert_eq!(trade.volume_in_usd::<f64>(), None);
+        let volume: TradeVolume = TradeVolume::from_trades([&trade], "0xUSDC");
+        assert_eq!(volume.buys, 0);
+    }
+
+    #[test]
+    fn test_trade_volume() {
+        let trades = vec![
+            trade("buy", "0xWETH", "0xUSDC", "100"),
+            trade("sell", "0xUSDC", "0xWETH", "40"),
+            trade("buy", "0xWETH", "0xUSDC", "10.5"),
+            trade("buy", "0xWETH", "0xDAI", "1000"),
+        ];
+        let volume: TradeVolume = TradeVolume::from_trades(&trades, "0xusdc");
+        assert_eq!(volume.buys, 2);
+        assert_eq!(volume.sells, 1);
+        assert_eq!(volume.buy_volume_usd, 110.5);
+        assert_eq!(volume.net_volume_usd(), 70.5);
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_trade_volume_decimal() {
+        use crate::types::BigDecimal;
+
+        let trades = vec![
+            trade("buy", "0xWETH", "0xUSDC", "0.1"),
+            trade("buy", "0xWETH", "0xUSDC", "0.2"),
+            trade("sell", "0xUSDC", "0xWETH", "0.3"),
+        ];
+        let volume = TradeVolume::<BigDecimal>::from_trades(&trades, "0xusdc");
+        assert_eq!(volume.buy_volume_usd, "0.3".parse::<BigDecimal>().unwrap());
+        assert_eq!(volume.net_volume_usd(), BigDecimal::default());
+        assert_eq!(
+            trades[0].amount_of::<BigDecimal>("0xUSDC"),
+            Some(BigDecimal::from(7500))
+        );
+    }
+
+    #[cfg(feature = "datetime")]
+    #[test]
+    fn test_trades_in_range() {
+        use chrono::TimeZone;
+
+        let trades: Vec<Trade> = ["2024-03-01T12:00:00Z", "2024-03-02T12:00:00Z", "invalid"]
+            .iter()
+            .map(|ts| {
+                let mut trade = trade("buy", "0xWETH", "0xUSDC", "1");
+                trade.attributes.block_timestamp = ts.to_string();
+                trade
+            })
+            .collect();
+        let start = Utc.with_ymd_and_hms(2024, 3, 1, 0, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2024, 3, 2, 0, 0, 0).unwrap();
+        assert!(trades[0].is_between(start, end));
+        assert!(!trades[1].is_between(start, end));
+        assert_eq!(Trade::in_range(&trades, start..end), vec![&trades[0]]);
+        assert_eq!(Trade::in_range(&trades, start..).len(), 2);
+        assert!(!trades[2].is_in(&(..)));
+    }
 }
@@ -0,0 +1,63 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::decimal_accessors;
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct TopTraders {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub attributes: Attributes,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "schema", schemars(rename = "TopTradersAttributes"))]
+#[non_exhaustive]
+pub struct Attributes {
+    pub traders: Vec<Trader>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[non_exhaustive]
+pub struct Trader {
+    pub address: String,
+    pub name: Option<String>,
+    pub label: Option<String>,
+    #[serde(rename = "type")]
+    pub type_field: Option<String>,
+    pub realized_pnl_usd: Option<String>,
+    pub unrealized_pnl_usd: Option<String>,
+    pub token_balance: Option<String>,
+    pub average_buy_price_usd: Option<String>,
+    pub average_sell_price_usd: Option<String>,
+    pub total_buy_count: Option<i64>,
+    pub total_sell_count: Option<i64>,
+    pub total_buy_token_amount: Option<String>,
+    pub total_sell_token_amount: Option<String>,
+    pub total_buy_usd: Option<String>,
+    pub total_sell_usd: Option<String>,
+    pub explorer_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+decimal_accessors!(Trader {
+    realized_pnl_usd,
+    unrealized_pnl_usd,
+    token_balance,
+    average_buy_price_usd,
+    average_sell_price_usd,
+    total_buy_token_amount,
+    total_sell_token_amount,
+    total_buy_usd,
+    total_sell_usd,
+});
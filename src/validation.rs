@@ -2,6 +2,8 @@ use crate::limits::{
     MAX_ADDRESSES, MAX_PAGE, OHLCV_LIMIT, VALID_CURRENCIES, VALID_DAY_AGGREGATES,
     VALID_HOUR_AGGREGATES, VALID_MINUTE_AGGREGATES, VALID_TIMEFRAMES, VALID_TOKENS,
 };
+use crate::types::trade::Trade;
+use crate::Error;
 
 pub fn check_page(page: &i32) {
     if page > &MAX_PAGE {
@@ -60,6 +62,29 @@ pub fn check_token(token: &str) {
     }
 }
 
+/// Fail before sending a request to a paid endpoint without an API key.
+pub fn check_api_key(api_key: &Option<String>) -> Result<(), Error> {
+    if api_key.is_none() {
+        log::error!("endpoint requires an API key, see GeckoTerminalAPI::with_api_key");
+        return Err(Error::MissingApiKey);
+    }
+    Ok(())
+}
+
+pub fn check_trade_order(trades: &mut [Trade]) {
+    let sorted = trades
+        .windows(2)
+        .all(|w| w[0].attributes.block_timestamp >= w[1].attributes.block_timestamp);
+    if !sorted {
+        log::warn!("trades not sorted by block timestamp, sorting");
+        trades.sort_by(|a, b| {
+            b.attributes
+                .block_timestamp
+                .cmp(&a.attributes.block_timestamp)
+        });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,4 +141,24 @@ mod tests {
         let token = "eth";
         check_token(token);
     }
+
+    #[test]
+    fn test_check_api_key() {
+        assert!(matches!(check_api_key(&None), Err(Error::MissingApiKey)));
+        assert!(check_api_key(&Some("CG-key".to_string())).is_ok());
+    }
+
+    #[test]
+    fn test_check_trade_order() {
+        let mut trades: Vec<Trade> = ["2024-03-01T10:00:00Z", "2024-03-01T12:00:00Z"]
+            .iter()
+            .map(|ts| {
+                let mut trade = Trade::default();
+                trade.attributes.block_timestamp = ts.to_string();
+                trade
+            })
+            .collect();
+        check_trade_order(&mut trades);
+        assert_eq!(trades[0].attributes.block_timestamp, "2024-03-01T12:00:00Z");
+    }
 }
@@ -0,0 +1,190 @@
+//! Offline schema regression tests against the responses in `tests/fixtures`.
+//!
+//! Each fixture must deserialize into its endpoint's response type, survive a serialize and
+//! deserialize roundtrip unchanged, and lose none of its fields on the way. Fixture fields
+//! must be modeled by the structs rather than end up in an `extra` map. Refresh the
+//! fixtures, or capture the ones of endpoints that have none yet, with
+//! `cargo run --example refresh_fixtures -- --write`.
+
+use std::fmt::Debug;
+use std::fs;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use geckoterminal_rs::types::{
+    category::Category,
+    dex::Dex,
+    holder::TopHolders,
+    network::Network,
+    ohlcv::OHLCV,
+    pool::Pool,
+    response::{GeckoTerminalResponse, Included},
+    simple::TokenPrice,
+    token::Token,
+    token_info::TokenInfo,
+    trade::{Trade, TradeSide},
+    trader::TopTraders,
+};
+
+fn load(name: &str) -> Value {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(format!("{name}.json"));
+    let body = fs::read_to_string(&path).unwrap_or_else(|err| panic!("{path:?}: {err}"));
+    serde_json::from_str(&body).unwrap()
+}
+
+/// Assert that every field of `expected` is present in `actual`, recursing into objects and
+/// arrays.
+fn assert_fields_kept(expected: &Value, actual: &Value, path: &str) {
+    match (expected, actual) {
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, value) in expected {
+                let field = format!("{path}.{key}");
+                let kept = actual
+                    .get(key)
+                    .unwrap_or_else(|| panic!("field {field} was dropped"));
+                assert_fields_kept(value, kept, &field);
+            }
+        }
+        (Value::Array(expected), Value::Array(actual)) => {
+            assert_eq!(expected.len(), actual.len(), "length of {path} changed");
+            for (i, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                assert_fields_kept(expected, actual, &format!("{path}[{i}]"));
+            }
+        }
+        _ => {}
+    }
+}
+
+fn roundtrip<T>(name: &str) -> T
+where
+    T: DeserializeOwned + Serialize + PartialEq + Debug,
+{
+    let fixture = load(name);
+    let typed: T = serde_json::from_value(fixture.clone())
+        .unwrap_or_else(|err| panic!("{name} does not deserialize: {err}"));
+    let serialized = serde_json::to_value(&typed).unwrap();
+    assert_fields_kept(&fixture, &serialized, name);
+    let again: T = serde_json::from_value(serialized).unwrap();
+    assert_eq!(typed, again, "{name} changed in a roundtrip");
+    typed
+}
+
+#[test]
+fn test_networks() {
+    let resp = roundtrip::<GeckoTerminalResponse<Vec<Network>>>("networks");
+    assert_eq!(resp.data[1].id, "polygon_pos");
+    assert!(resp.links.unwrap().next.is_some());
+    assert!(resp.data.iter().all(|n| n.attributes.extra.is_empty()));
+}
+
+#[test]
+fn test_network_dexes() {
+    let resp = roundtrip::<GeckoTerminalResponse<Vec<Dex>>>("network_dexes");
+    assert_eq!(resp.data[0].attributes.name, "Uniswap V3");
+    assert!(resp.data.iter().all(|dex| dex.attributes.extra.is_empty()));
+}
+
+#[test]
+fn test_network_pools() {
+    let resp = roundtrip::<GeckoTerminalResponse<Vec<Pool>>>("network_pools");
+    let pool = &resp.data[0];
+    assert_eq!(pool.id.network, "eth");
+    assert_eq!(pool.base_token(&resp).unwrap().attributes.symbol, "USDC");
+    assert_eq!(pool.dex(&resp).unwrap().attributes.name, "Uniswap V3");
+    assert_eq!(pool.network(&resp).unwrap().id, "eth");
+    assert!(resp
+        .data
+        .iter()
+        .all(|pool| pool.attributes.extra.is_empty()));
+    assert!(resp.data[1]
+        .attributes
+        .base_token_price_quote_token
+        .is_none());
+    assert!(resp
+        .included
+        .unwrap()
+        .iter()
+        .all(|item| !matches!(item, Included::Unknown(_))));
+}
+
+#[test]
+fn test_network_pool_address() {
+    let resp = roundtrip::<GeckoTerminalResponse<Pool>>("network_pool_address");
+    assert!(resp.data.quote_token(&resp).is_some());
+}
+
+#[test]
+fn test_network_token() {
+    let resp = roundtrip::<GeckoTerminalResponse<Token>>("network_token");
+    let top_pools = resp.data.top_pools(&resp);
+    assert_eq!(top_pools.len(), 1);
+    assert!(top_pools[0].attributes.token_price_usd.is_some());
+    assert!(top_pools[0].attributes.extra.is_empty());
+    assert!(resp.data.attributes.extra.is_empty());
+}
+
+#[test]
+fn test_network_tokens_address_info() {
+    let resp = roundtrip::<GeckoTerminalResponse<TokenInfo>>("network_tokens_address_info");
+    assert_eq!(resp.data.attributes.websites.len(), 1);
+    assert!(resp.data.attributes.extra.is_empty());
+}
+
+#[test]
+fn test_token_info_recently_updated() {
+    let resp = roundtrip::<GeckoTerminalResponse<Vec<TokenInfo>>>("token_info_recently_updated");
+    assert!(resp.data[0].attributes.image_url.is_none());
+}
+
+#[test]
+fn test_network_addresses_token_price() {
+    let resp = roundtrip::<GeckoTerminalResponse<TokenPrice>>("network_addresses_token_price");
+    assert_eq!(resp.data.attributes.token_prices.len(), 3);
+}
+
+#[test]
+fn test_network_pool_trades() {
+    let resp = roundtrip::<GeckoTerminalResponse<Vec<Trade>>>("network_pool_trades");
+    assert_eq!(resp.data[0].attributes.kind, TradeSide::Buy);
+    assert_eq!(resp.data[1].attributes.kind, TradeSide::Sell);
+    assert!(resp
+        .data
+        .iter()
+        .all(|trade| trade.attributes.extra.is_empty()));
+}
+
+#[test]
+fn test_network_pool_ohlcv() {
+    let resp = roundtrip::<GeckoTerminalResponse<OHLCV>>("network_pool_ohlcv");
+    assert_eq!(resp.data.attributes.ohlcv_list.len(), 2);
+    assert_eq!(resp.meta.unwrap().base.symbol, "USDC");
+}
+
+#[test]
+fn test_categories() {
+    let resp = roundtrip::<GeckoTerminalResponse<Vec<Category>>>("categories");
+    assert_eq!(resp.data[0].attributes.h24_tx_count, Some(281733));
+    assert!(resp.data[0].attributes.extra.is_empty());
+}
+
+#[test]
+fn test_network_token_top_holders() {
+    let resp = roundtrip::<GeckoTerminalResponse<TopHolders>>("network_token_top_holders");
+    assert_eq!(resp.data.attributes.holders[0].rank, 1);
+    assert!(resp.data.attributes.holders[0].extra.is_empty());
+}
+
+#[test]
+fn test_network_token_top_traders() {
+    let resp = roundtrip::<GeckoTerminalResponse<TopTraders>>("network_token_top_traders");
+    assert_eq!(
+        resp.data.attributes.traders[0].type_field.as_deref(),
+        Some("bot")
+    );
+    assert!(resp.data.attributes.traders[0].extra.is_empty());
+}